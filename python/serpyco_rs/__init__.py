@@ -1,3 +1,14 @@
+from ._impl import UNSET, CancellationToken, dump_duration, parse_duration, self_check
 from ._json_schema import JsonschemaRSValidator, Validator
-from ._main import Serializer
-from .exceptions import SchemaValidationError, ValidationError
+from ._main import Serializer, configure, get_or_create_serializer
+from .exceptions import (
+    ConstraintError,
+    LoadCancelled,
+    LoadTimeout,
+    RequiredFieldError,
+    SchemaValidationError,
+    TypeMismatchError,
+    ValidationError,
+)
+from .money import Money
+from .secret import Secret, SecretBytes, SecretStr
@@ -0,0 +1,227 @@
+import re
+from collections.abc import Iterator
+from functools import singledispatch
+from typing import Any, Optional, Union
+
+from . import _describe as describe
+from .exceptions import ErrorItem, SchemaValidationError
+
+
+_DATETIME_FORMATS = ("iso", "iso_seconds", "epoch", "epoch_ms", "rfc2822")
+_STRING_FORMATS = ("phone", "iso3166-alpha2", "bcp47", "iso4217")
+
+
+def check_constraints(t: describe.Type) -> None:
+    errors = list(_check(t, ""))
+    if errors:
+        raise SchemaValidationError(errors)
+
+
+@singledispatch
+def _check(_: Any, path: str) -> Iterator[ErrorItem]:
+    yield from ()
+
+
+@_check.register
+def _(arg: describe.IntegerType, path: str) -> Iterator[ErrorItem]:
+    yield from _check_bounds(arg, path)
+    yield from _check_multiple_of(arg.multiple_of, path)
+
+
+@_check.register
+def _(arg: describe.FloatType, path: str) -> Iterator[ErrorItem]:
+    yield from _check_bounds(arg, path)
+    yield from _check_multiple_of(arg.multiple_of, path)
+
+
+@_check.register
+def _(arg: describe.DecimalType, path: str) -> Iterator[ErrorItem]:
+    yield from _check_bounds(arg, path)
+    yield from _check_multiple_of(arg.multiple_of, path)
+    if arg.places is not None and arg.places < 0:
+        yield _error(f"places ({arg.places}) must be non-negative", path, "places")
+    if arg.max_digits is not None and arg.max_digits <= 0:
+        yield _error(f"max_digits ({arg.max_digits}) must be greater than 0", path, "maxDigits")
+    if arg.places is not None and arg.max_digits is not None and arg.places > arg.max_digits:
+        yield _error(f"places ({arg.places}) is greater than max_digits ({arg.max_digits})", path, "places")
+
+
+@_check.register
+def _(arg: describe.StringType, path: str) -> Iterator[ErrorItem]:
+    yield from _check_length(arg.min_length, arg.max_length, path, "minLength", "maxLength")
+    if arg.format is not None and arg.format not in _STRING_FORMATS:
+        yield _error(f"format {arg.format!r} is not supported", path, "format")
+    if arg.to_lower and arg.to_upper:
+        yield _error("to_lower and to_upper are mutually exclusive", path, "toUpper")
+
+
+@_check.register
+def _(arg: describe.SecretType, path: str) -> Iterator[ErrorItem]:
+    if arg.format is not None and (arg.format != "jwt" or arg.is_bytes):
+        yield _error(f"format {arg.format!r} is not supported", path, "format")
+
+
+@_check.register
+def _(arg: describe.SemVerType, path: str) -> Iterator[ErrorItem]:
+    min_key = _semver_key(arg.min) if arg.min is not None else None
+    max_key = _semver_key(arg.max) if arg.max is not None else None
+    if arg.min is not None and min_key is None:
+        yield _error(f"min ({arg.min!r}) is not a valid semantic version", path, "minimum")
+    if arg.max is not None and max_key is None:
+        yield _error(f"max ({arg.max!r}) is not a valid semantic version", path, "maximum")
+    if min_key is not None and max_key is not None and min_key > max_key:
+        yield _error(f"min ({arg.min}) is greater than max ({arg.max})", path, "minimum")
+
+
+@_check.register
+def _(arg: describe.DateTimeType, path: str) -> Iterator[ErrorItem]:
+    yield from _check_range(arg.min, arg.max, path)
+    if not arg.formats:
+        yield _error("formats must not be empty", path, "formats")
+    for fmt in (*arg.formats, *([arg.dump_format] if arg.dump_format is not None else [])):
+        if fmt not in _DATETIME_FORMATS and "%" not in fmt:
+            names = ", ".join(repr(name) for name in _DATETIME_FORMATS)
+            yield _error(f"format {fmt!r} is not {names} or a strftime pattern", path, "formats")
+
+
+@_check.register
+def _(arg: describe.DateType, path: str) -> Iterator[ErrorItem]:
+    yield from _check_range(arg.min, arg.max, path)
+
+
+@_check.register
+def _(arg: describe.TimeType, path: str) -> Iterator[ErrorItem]:
+    yield from _check_range(arg.min, arg.max, path)
+
+
+@_check.register
+def _(arg: describe.OptionalType, path: str) -> Iterator[ErrorItem]:
+    yield from _check(arg.inner, path)
+
+
+@_check.register
+def _(arg: describe.EnumType, path: str) -> Iterator[ErrorItem]:
+    if arg.dump_format not in {"str", "value", "name"}:
+        yield _error(f"dump format {arg.dump_format!r} is not 'str', 'value' or 'name'", path, "dumpFormat")
+
+
+@_check.register
+def _(arg: describe.FlagType, path: str) -> Iterator[ErrorItem]:
+    if arg.dump_format not in {"value", "name"}:
+        yield _error(f"dump format {arg.dump_format!r} is not 'value' or 'name'", path, "dumpFormat")
+
+
+@_check.register
+def _(arg: describe.JsonStringType, path: str) -> Iterator[ErrorItem]:
+    yield from _check(arg.inner, path)
+
+
+@_check.register
+def _(arg: describe.ArrayType, path: str) -> Iterator[ErrorItem]:
+    item_type = arg.item_type
+    item_fields = [(arg.unique_by, "uniqueBy")]
+    if arg.sorted_by is not None:
+        field, order = arg.sorted_by
+        item_fields.append((field, "sortedBy"))
+        if order not in {"asc", "desc"}:
+            yield _error(f"order {order!r} is not 'asc' or 'desc'", path, "sortedBy")
+    if isinstance(item_type, describe.EntityType):
+        for field, schema_path in item_fields:
+            if field is not None and all(f.name != field for f in item_type.fields):
+                yield _error(f"{item_type.cls.__name__} has no field {field!r}", path, schema_path)
+    yield from _check(item_type, path)
+
+
+@_check.register
+def _(arg: describe.SetType, path: str) -> Iterator[ErrorItem]:
+    yield from _check(arg.item_type, path)
+
+
+@_check.register
+def _(arg: describe.DictionaryType, path: str) -> Iterator[ErrorItem]:
+    yield from _check(arg.key_type, path)
+    yield from _check(arg.value_type, path)
+
+
+@_check.register
+def _(arg: describe.TupleType, path: str) -> Iterator[ErrorItem]:
+    yield from _check_length(arg.min_length, arg.max_length, path, "minItems", "maxItems")
+    for item in arg.item_types:
+        yield from _check(item, path)
+
+
+@_check.register
+def _(arg: describe.EntityType, path: str) -> Iterator[ErrorItem]:
+    extra_keys_fields = [field.name for field in arg.fields if field.is_extra_keys]
+    if len(extra_keys_fields) > 1:
+        yield _error(f"{arg.name} has more than one ExtraKeys field: {extra_keys_fields}", path, "additionalProperties")
+    for field in arg.fields:
+        field_path = _join(path, field.dict_key)
+        if field.is_extra_keys and not (
+            isinstance(field.type, describe.DictionaryType) and isinstance(field.type.key_type, describe.StringType)
+        ):
+            message = f"ExtraKeys field {field.name!r} is not a dict with str keys"
+            yield _error(message, field_path, "additionalProperties")
+        if field.skip_if_default and field.default is describe.NOT_SET and field.default_factory is describe.NOT_SET:
+            yield _error(f"skip_if_default requires a default value of {field.name!r}", field_path, "skipIfDefault")
+        yield from _check(field.type, field_path)
+
+
+def _check_bounds(
+    arg: Union[describe.IntegerType, describe.FloatType, describe.DecimalType],
+    path: str,
+) -> Iterator[ErrorItem]:
+    if arg.min is not None and arg.max is not None and arg.min > arg.max:
+        yield _error(f"min ({arg.min}) is greater than max ({arg.max})", path, "minimum")
+
+    open_bounds = (
+        ("min", arg.min, "exclusive_max", arg.exclusive_max, "minimum"),
+        ("exclusive_min", arg.exclusive_min, "max", arg.max, "exclusiveMinimum"),
+        ("exclusive_min", arg.exclusive_min, "exclusive_max", arg.exclusive_max, "exclusiveMinimum"),
+    )
+    for lower_name, lower, upper_name, upper, schema_path in open_bounds:
+        if lower is not None and upper is not None and lower >= upper:
+            yield _error(f"{lower_name} ({lower}) must be less than {upper_name} ({upper})", path, schema_path)
+
+
+def _check_range(min_value: Any, max_value: Any, path: str) -> Iterator[ErrorItem]:
+    if min_value is not None and max_value is not None and min_value > max_value:
+        yield _error(f"min ({min_value}) is greater than max ({max_value})", path, "minimum")
+
+
+def _semver_key(version: str) -> Optional[tuple[Any, ...]]:
+    match = re.match(describe.SEMVER_PATTERN, version)
+    if not match:
+        return None
+    major, minor, patch, pre, _ = match.groups()
+    # releases go after their pre-releases, numeric identifiers before alphanumeric ones
+    pre_key = [(0, int(part), "") if part.isdigit() else (1, 0, part) for part in pre.split(".")] if pre else []
+    return int(major), int(minor), int(patch), pre is None, pre_key
+
+
+def _check_length(
+    min_length: Optional[int],
+    max_length: Optional[int],
+    path: str,
+    min_schema_path: str,
+    max_schema_path: str,
+) -> Iterator[ErrorItem]:
+    if min_length is not None and min_length < 0:
+        yield _error(f"min_length ({min_length}) must be non-negative", path, min_schema_path)
+    if max_length is not None and max_length < 0:
+        yield _error(f"max_length ({max_length}) must be non-negative", path, max_schema_path)
+    if min_length is not None and max_length is not None and min_length > max_length:
+        yield _error(f"min_length ({min_length}) is greater than max_length ({max_length})", path, min_schema_path)
+
+
+def _check_multiple_of(multiple_of: Any, path: str) -> Iterator[ErrorItem]:
+    if multiple_of is not None and multiple_of <= 0:
+        yield _error(f"multiple_of ({multiple_of}) must be greater than 0", path, "multipleOf")
+
+
+def _error(message: str, path: str, schema_path: str) -> ErrorItem:
+    return ErrorItem(message=message, instance_path=path, schema_path=schema_path)
+
+
+def _join(path: str, key: str) -> str:
+    return f"{path}/{key}" if path else key
@@ -1,9 +1,14 @@
+import copy
 import dataclasses
+import inspect
 import sys
-from collections.abc import Callable, Iterable, Mapping, Sequence
-from datetime import date, datetime, time
+from collections.abc import Callable, Iterable, Iterator, Mapping, Sequence
+from datetime import date, datetime, time, timedelta, tzinfo
 from decimal import Decimal
-from enum import Enum, IntEnum
+from enum import Enum, Flag, IntEnum
+from ipaddress import IPv4Address, IPv4Interface, IPv4Network, IPv6Address, IPv6Interface, IPv6Network
+from pathlib import PurePath
+from types import MemberDescriptorType
 from typing import Annotated, Any, Optional, TypeVar, Union, cast, get_origin, get_type_hints, overload
 from uuid import UUID
 
@@ -11,7 +16,63 @@ from attributes_doc import get_attributes_doc
 from typing_extensions import assert_never
 
 from ._utils import to_camelcase
-from .metadata import FiledFormat, Format, Max, MaxLength, Min, MinLength, NoFormat, Places
+from .metadata import (
+    AbsolutePath,
+    Aliases,
+    AssumeTimezone,
+    BytesEncoding,
+    BytesFormat,
+    CanonicalUUID,
+    ClampYear,
+    Computed,
+    ConvertToUTC,
+    DateTimeDumpFormat,
+    DateTimeFormats,
+    DumpKey,
+    DumpPrecision,
+    DurationFormat,
+    EnumByName,
+    EnumDumpFormat,
+    ErrorMessage,
+    Examples,
+    ExclusiveMax,
+    ExclusiveMin,
+    ExtraKeys,
+    FiledFormat,
+    Format,
+    JsonString,
+    LenientDate,
+    LenientUUID,
+    LoadKey,
+    Max,
+    MaxDigits,
+    MaxLength,
+    Min,
+    MinLength,
+    MultipleOf,
+    NoFormat,
+    NoParentRefs,
+    Places,
+    PrefixedInt,
+    RedactSecret,
+    RequireAware,
+    SkipIf,
+    SkipIfDefault,
+    SortedBy,
+    StringFormat,
+    StripWhitespace,
+    TimeDeltaFormat,
+    TimePrecision,
+    ToLower,
+    ToUpper,
+    UniqueBy,
+    UniqueItems,
+    UUIDDumpFormat,
+    UUIDFormat,
+    ZSuffix,
+)
+from .money import Money
+from .secret import Secret, SecretBytes, SecretStr
 
 if sys.version_info >= (3, 10):  # pragma: no cover
     from types import UnionType
@@ -26,8 +87,14 @@ except ImportError:  # pragma: no cover
 
 _NoneType = type(None)
 
+# attrs field metadata key with a list of serpyco_rs.metadata markers, e.g. attr.ib(metadata={"serpyco_rs": [Min(0)]})
+ATTRS_METADATA_KEY = "serpyco_rs"
+
 _T = TypeVar("_T")
 
+# Called with a type serpyco-rs doesn't support, returns an encoder for it or None.
+FallbackEncoderFactory = Callable[[Any], Optional[Any]]
+
 
 class NotSet:
     def __repr__(self) -> str:
@@ -39,37 +106,53 @@ NOT_SET = NotSet()
 
 @dataclasses.dataclass
 class Type:
-    pass
+    # raw values of Examples metadata, not a dataclass field to keep required fields of the subclasses
+    examples = None  # type: Optional[list[Any]]
 
 
 @dataclasses.dataclass
 class IntegerType(Type):
     min: Optional[int] = None
     max: Optional[int] = None
+    exclusive_min: Optional[int] = None
+    exclusive_max: Optional[int] = None
+    multiple_of: Optional[int] = None
+    prefixed: bool = False
 
 
 @dataclasses.dataclass
 class StringType(Type):
     min_length: Optional[int] = None
     max_length: Optional[int] = None
+    format: Optional[str] = None
+    strip_whitespace: bool = False
+    to_lower: bool = False
+    to_upper: bool = False
 
 
 @dataclasses.dataclass
 class BytesType(Type):
-    pass
+    encoding: BytesEncoding = BytesEncoding.raw
 
 
 @dataclasses.dataclass
 class FloatType(Type):
     min: Optional[float] = None
     max: Optional[float] = None
+    exclusive_min: Optional[float] = None
+    exclusive_max: Optional[float] = None
+    multiple_of: Optional[float] = None
 
 
 @dataclasses.dataclass
 class DecimalType(Type):
     places: Optional[int] = None
+    max_digits: Optional[int] = None
     min: Optional[Decimal] = None
     max: Optional[Decimal] = None
+    exclusive_min: Optional[Decimal] = None
+    exclusive_max: Optional[Decimal] = None
+    multiple_of: Optional[Decimal] = None
 
 
 @dataclasses.dataclass
@@ -79,27 +162,113 @@ class BooleanType(Type):
 
 @dataclasses.dataclass
 class UUIDType(Type):
-    pass
+    as_str: bool = False
+    lenient: bool = False
+    dump_format: UUIDFormat = UUIDFormat.canonical
+
+
+@dataclasses.dataclass
+class IPAddressType(Type):
+    cls: type[Union[IPv4Address, IPv6Address]]
+    version: int
+
+
+@dataclasses.dataclass
+class IPNetworkType(Type):
+    cls: type[Union[IPv4Network, IPv6Network]]
+    version: int
+
+
+@dataclasses.dataclass
+class IPInterfaceType(Type):
+    cls: type[Union[IPv4Interface, IPv6Interface]]
+    version: int
+
+
+@dataclasses.dataclass
+class MoneyType(Type):
+    cls: type[Money]
+
+
+@dataclasses.dataclass
+class SecretType(Type):
+    cls: type[Union[Secret, SecretBytes]]
+    redact: bool = False
+    placeholder: str = "***"
+    is_bytes: bool = False
+    format: Optional[str] = None
+
+
+# https://semver.org/#is-there-a-suggested-regular-expression-regex-to-check-a-semver-string
+SEMVER_PATTERN = (
+    r"^(0|[1-9][0-9]*)\.(0|[1-9][0-9]*)\.(0|[1-9][0-9]*)"
+    r"(?:-((?:0|[1-9][0-9]*|[0-9]*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9][0-9]*|[0-9]*[a-zA-Z-][0-9a-zA-Z-]*))*))?"
+    r"(?:\+([0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*))?$"
+)
+
+
+@dataclasses.dataclass
+class SemVerType(Type):
+    min: Optional[str] = None
+    max: Optional[str] = None
+
+
+@dataclasses.dataclass
+class PathType(Type):
+    cls: type[PurePath]
+    absolute: bool = False
+    allow_parent_refs: bool = True
 
 
 @dataclasses.dataclass
 class TimeType(Type):
-    pass
+    min: Optional[time] = None
+    max: Optional[time] = None
+    precision: Optional[TimePrecision] = None
+    round_precision: bool = False
 
 
 @dataclasses.dataclass
 class DateTimeType(Type):
-    pass
+    formats: tuple[str, ...] = ("iso",)
+    dump_format: Optional[str] = None
+    z_suffix: bool = False
+    precision: Optional[TimePrecision] = None
+    round_precision: bool = False
+    require_aware: bool = False
+    assume_timezone: Optional[tzinfo] = None
+    convert_to_utc: bool = False
+    min: Optional[datetime] = None
+    max: Optional[datetime] = None
+    clamp_year: bool = False
 
 
 @dataclasses.dataclass
 class DateType(Type):
-    pass
+    lenient: bool = False
+    min: Optional[date] = None
+    max: Optional[date] = None
+    clamp_year: bool = False
+
+
+@dataclasses.dataclass
+class TimeDurationType(Type):
+    format: DurationFormat = DurationFormat.iso8601
 
 
 @dataclasses.dataclass
 class EnumType(Type):
     cls: type[Union[Enum, IntEnum]]
+    load_names: bool = False
+    load_values: bool = True
+    dump_format: str = "str"
+
+
+@dataclasses.dataclass
+class FlagType(Type):
+    cls: type[Flag]
+    # "value" is the int bitmask, "name" the list of set member names
+    dump_format: str = "value"
 
 
 @dataclasses.dataclass
@@ -111,6 +280,21 @@ class EntityField:
     default: Any = NOT_SET
     default_factory: Union[Callable[[], Any], NotSet] = NOT_SET
     is_property: bool = False
+    # collects the unknown keys of the entity on load
+    is_extra_keys: bool = False
+    # False for fields set by __init__ itself, they aren't loaded with Serializer(use_init=True)
+    init: bool = True
+    # __init__ keyword argument, name is used when not set
+    init_name: Optional[str] = None
+    # member descriptor of a __slots__ attribute, sets it past __setattr__ of frozen classes
+    slot: Optional[MemberDescriptorType] = None
+    error_message: Optional[str] = None
+    aliases: list[str] = dataclasses.field(default_factory=list)
+    skip_if_default: bool = False
+    skip_if: Optional[Callable[[Any], bool]] = None
+    # direction specific keys, dict_key is used when not set
+    load_key: Optional[str] = None
+    dump_key: Optional[str] = None
 
 
 @dataclasses.dataclass
@@ -120,6 +304,9 @@ class EntityType(Type):
     fields: Sequence[EntityField]
     generics: Mapping[TypeVar, Any] = dataclasses.field(default_factory=dict)
     doc: Optional[str] = None
+    post_load: Optional[str] = None
+    pre_dump: Optional[Callable[[Any], Any]] = None
+    use_init: bool = False
 
 
 @dataclasses.dataclass
@@ -131,6 +318,16 @@ class OptionalType(Type):
 class ArrayType(Type):
     item_type: Type
     is_sequence: bool
+    unique_items: bool = False
+    unique_by: Optional[str] = None
+    sorted_by: Optional[tuple[str, str]] = None
+
+
+@dataclasses.dataclass
+class SetType(Type):
+    item_type: Type
+    is_frozen: bool
+    unique_items: bool = False
 
 
 @dataclasses.dataclass
@@ -143,6 +340,15 @@ class DictionaryType(Type):
 @dataclasses.dataclass
 class TupleType(Type):
     item_types: Sequence[Type]
+    # tuple[X, ...]: item_types holds the single item type, length is limited by min_length/max_length
+    variadic: bool = False
+    min_length: Optional[int] = None
+    max_length: Optional[int] = None
+
+
+@dataclasses.dataclass
+class JsonStringType(Type):
+    inner: Type
 
 
 @dataclasses.dataclass
@@ -150,6 +356,17 @@ class AnyType(Type):
     pass
 
 
+@dataclasses.dataclass
+class CustomType(Type):
+    cls: Any
+    encoder: Any  # has dump(value) and load(value) methods
+
+
+@dataclasses.dataclass
+class PydanticModelType(Type):
+    cls: Any  # pydantic.BaseModel subclass, loaded with model_validate and dumped with model_dump
+
+
 @dataclasses.dataclass
 class RecursionHolder(Type):
     cls: Any
@@ -163,11 +380,26 @@ class RecursionHolder(Type):
         raise RuntimeError("Recursive type not resolved")
 
 
-def describe_type(t: Any, state: Optional[dict[tuple[type, FiledFormat], Optional[Type]]] = None) -> Type:
+def describe_type(
+    t: Any,
+    state: Optional[dict[tuple[type, FiledFormat], Optional[Type]]] = None,
+    fallback_encoder_factory: Optional[FallbackEncoderFactory] = None,
+) -> Type:
     state = state or {}
     parameters: tuple[Any, ...] = ()
     args: tuple[Any, ...] = ()
     metadata = _get_annotated_metadata(t)
+    if examples := _find_metadata(metadata, Examples):
+        inner_metadata = [ann for ann in metadata if not isinstance(ann, Examples)]
+        inner_type = _wrap_annotated(inner_metadata)(t.__origin__)
+        # a copy, entities are shared by all the fields of their class
+        type_info = copy.copy(describe_type(inner_type, state, fallback_encoder_factory))
+        type_info.examples = list(examples.values)
+        return type_info
+    if _find_metadata(metadata, JsonString):
+        inner_metadata = [ann for ann in metadata if not isinstance(ann, JsonString)]
+        inner_type = _wrap_annotated(inner_metadata)(t.__origin__)
+        return JsonStringType(inner=describe_type(inner_type, state, fallback_encoder_factory))
     if get_origin(t) == Annotated:  # unwrap annotated
         t = t.__origin__
     if hasattr(t, "__origin__"):
@@ -187,6 +419,9 @@ def describe_type(t: Any, state: Optional[dict[tuple[type, FiledFormat], Optiona
     filed_format = _find_metadata(metadata, FiledFormat, NoFormat)
     annotation_wrapper = _wrap_annotated([filed_format])
 
+    def describe_inner(arg: Any) -> Type:
+        return describe_type(annotation_wrapper(arg), state, fallback_encoder_factory)
+
     if (t, filed_format) in state:
         return RecursionHolder(cls=t, name=_generate_name(t, filed_format), field_format=filed_format, state=state)
 
@@ -195,17 +430,20 @@ def describe_type(t: Any, state: Optional[dict[tuple[type, FiledFormat], Optiona
 
     if isinstance(t, type):
         simple_type_mapping: Mapping[type, type[Type]] = {
-            bytes: BytesType,
             bool: BooleanType,
-            date: DateType,
-            time: TimeType,
-            datetime: DateTimeType,
-            UUID: UUIDType,
         }
 
         if simple := simple_type_mapping.get(t):
             return simple()
 
+        if t is UUID or (t is str and _find_metadata(metadata, CanonicalUUID)):
+            uuid_format = _find_metadata(metadata, UUIDDumpFormat)
+            return UUIDType(
+                as_str=t is str,
+                lenient=_find_metadata(metadata, LenientUUID) is not None,
+                dump_format=uuid_format.format if uuid_format else UUIDFormat.canonical,
+            )
+
         number_type_mapping: Mapping[type, type[IntegerType] | type[FloatType]] = {
             int: IntegerType,
             float: FloatType,
@@ -214,59 +452,210 @@ def describe_type(t: Any, state: Optional[dict[tuple[type, FiledFormat], Optiona
         if number_type := number_type_mapping.get(t):
             min_meta = _find_metadata(metadata, Min)
             max_meta = _find_metadata(metadata, Max)
-            return number_type(
+            exclusive_min_meta = _find_metadata(metadata, ExclusiveMin)
+            exclusive_max_meta = _find_metadata(metadata, ExclusiveMax)
+            multiple_of_meta = _find_metadata(metadata, MultipleOf)
+            number = number_type(
                 min=cast(Any, min_meta.value) if min_meta else None,
                 max=cast(Any, max_meta.value) if max_meta else None,
+                exclusive_min=cast(Any, exclusive_min_meta.value) if exclusive_min_meta else None,
+                exclusive_max=cast(Any, exclusive_max_meta.value) if exclusive_max_meta else None,
+                multiple_of=cast(Any, multiple_of_meta.value) if multiple_of_meta else None,
             )
+            if isinstance(number, IntegerType):
+                number.prefixed = _find_metadata(metadata, PrefixedInt) is not None
+            return number
 
         if t is Decimal:
             min_meta = _find_metadata(metadata, Min)
             max_meta = _find_metadata(metadata, Max)
+            exclusive_min_meta = _find_metadata(metadata, ExclusiveMin)
+            exclusive_max_meta = _find_metadata(metadata, ExclusiveMax)
             places_meta = _find_metadata(metadata, Places)
+            max_digits_meta = _find_metadata(metadata, MaxDigits)
+            multiple_of_meta = _find_metadata(metadata, MultipleOf)
             return DecimalType(
                 min=cast(Decimal, min_meta.value) if min_meta else None,
                 max=cast(Decimal, max_meta.value) if max_meta else None,
+                exclusive_min=cast(Decimal, exclusive_min_meta.value) if exclusive_min_meta else None,
+                exclusive_max=cast(Decimal, exclusive_max_meta.value) if exclusive_max_meta else None,
                 places=places_meta.value if places_meta else None,
+                max_digits=max_digits_meta.value if max_digits_meta else None,
+                multiple_of=Decimal(str(multiple_of_meta.value)) if multiple_of_meta else None,
+            )
+
+        if t is Money:
+            return MoneyType(cls=t)
+
+        if t is Secret:
+            format_meta = _find_metadata(metadata, StringFormat)
+            return SecretType(
+                cls=t,
+                redact=_find_metadata(metadata, RedactSecret) is not None,
+                format=format_meta.format if format_meta else None,
             )
 
+        if t in {SecretStr, SecretBytes}:
+            format_meta = _find_metadata(metadata, StringFormat)
+            return SecretType(
+                cls=t,
+                redact=True,
+                placeholder="**********",
+                is_bytes=t is SecretBytes,
+                format=format_meta.format if format_meta else None,
+            )
+
+        if t in {datetime, date, time}:
+            min_meta = _find_metadata(metadata, Min)
+            max_meta = _find_metadata(metadata, Max)
+            min_value = cast(Any, min_meta.value) if min_meta else None
+            max_value = cast(Any, max_meta.value) if max_meta else None
+            precision_meta = _find_metadata(metadata, DumpPrecision)
+            precision = precision_meta.precision if precision_meta else None
+            round_precision = precision_meta.round if precision_meta else False
+
+            if t is datetime:
+                formats_meta = _find_metadata(metadata, DateTimeFormats)
+                dump_format_meta = _find_metadata(metadata, DateTimeDumpFormat)
+                assume_timezone_meta = _find_metadata(metadata, AssumeTimezone)
+                return DateTimeType(
+                    formats=tuple(formats_meta.formats) if formats_meta else ("iso",),
+                    dump_format=dump_format_meta.format if dump_format_meta else None,
+                    z_suffix=_find_metadata(metadata, ZSuffix) is not None,
+                    precision=precision,
+                    round_precision=round_precision,
+                    require_aware=_find_metadata(metadata, RequireAware) is not None,
+                    assume_timezone=assume_timezone_meta.tz if assume_timezone_meta else None,
+                    convert_to_utc=_find_metadata(metadata, ConvertToUTC) is not None,
+                    min=min_value,
+                    max=max_value,
+                    clamp_year=_find_metadata(metadata, ClampYear) is not None,
+                )
+
+            if t is date:
+                return DateType(
+                    lenient=_find_metadata(metadata, LenientDate) is not None,
+                    min=min_value,
+                    max=max_value,
+                    clamp_year=_find_metadata(metadata, ClampYear) is not None,
+                )
+
+            return TimeType(min=min_value, max=max_value, precision=precision, round_precision=round_precision)
+
+        if t is timedelta:
+            duration_format = _find_metadata(metadata, TimeDeltaFormat)
+            return TimeDurationType(format=duration_format.format if duration_format else DurationFormat.iso8601)
+
+        if t in {IPv4Address, IPv6Address}:
+            return IPAddressType(cls=t, version=4 if t is IPv4Address else 6)
+
+        if t in {IPv4Network, IPv6Network}:
+            return IPNetworkType(cls=t, version=4 if t is IPv4Network else 6)
+
+        if t in {IPv4Interface, IPv6Interface}:
+            return IPInterfaceType(cls=t, version=4 if t is IPv4Interface else 6)
+
+        if issubclass(t, PurePath):
+            return PathType(
+                cls=t,
+                absolute=_find_metadata(metadata, AbsolutePath) is not None,
+                allow_parent_refs=_find_metadata(metadata, NoParentRefs) is None,
+            )
+
+        if t is bytes:
+            bytes_format = _find_metadata(metadata, BytesFormat)
+            return BytesType(encoding=bytes_format.encoding if bytes_format else BytesEncoding.raw)
+
         if t is str:
             min_length_meta = _find_metadata(metadata, MinLength)
             max_length_meta = _find_metadata(metadata, MaxLength)
+            format_meta = _find_metadata(metadata, StringFormat)
+            if format_meta and format_meta.format == "semver":
+                min_meta = _find_metadata(metadata, Min)
+                max_meta = _find_metadata(metadata, Max)
+                return SemVerType(
+                    min=cast(Any, min_meta.value) if min_meta else None,
+                    max=cast(Any, max_meta.value) if max_meta else None,
+                )
             return StringType(
                 min_length=min_length_meta.value if min_length_meta else None,
                 max_length=max_length_meta.value if max_length_meta else None,
+                format=format_meta.format if format_meta else None,
+                strip_whitespace=_find_metadata(metadata, StripWhitespace) is not None,
+                to_lower=_find_metadata(metadata, ToLower) is not None,
+                to_upper=_find_metadata(metadata, ToUpper) is not None,
             )
 
         if t in {Sequence, list}:
+            unique_by_meta = _find_metadata(metadata, UniqueBy)
+            sorted_by_meta = _find_metadata(metadata, SortedBy)
             return ArrayType(
-                item_type=(describe_type(annotation_wrapper(args[0]), state) if args else AnyType()),
+                item_type=(describe_inner(args[0]) if args else AnyType()),
                 is_sequence=t is Sequence,
+                unique_items=_find_metadata(metadata, UniqueItems) is not None,
+                unique_by=unique_by_meta.field if unique_by_meta else None,
+                sorted_by=(sorted_by_meta.field, sorted_by_meta.order) if sorted_by_meta else None,
+            )
+
+        if t in {set, frozenset}:
+            return SetType(
+                item_type=(describe_inner(args[0]) if args else AnyType()),
+                is_frozen=t is frozenset,
+                unique_items=_find_metadata(metadata, UniqueItems) is not None,
             )
 
         if t in {Mapping, dict}:
             return DictionaryType(
-                key_type=(describe_type(annotation_wrapper(args[0]), state) if args else AnyType()),
-                value_type=(describe_type(annotation_wrapper(args[1]), state) if args else AnyType()),
+                key_type=(describe_inner(args[0]) if args else AnyType()),
+                value_type=(describe_inner(args[1]) if args else AnyType()),
                 is_mapping=t is Mapping,
             )
 
         if t is tuple:
+            if len(args) == 2 and args[1] is Ellipsis:
+                min_length_meta = _find_metadata(metadata, MinLength)
+                max_length_meta = _find_metadata(metadata, MaxLength)
+                return TupleType(
+                    item_types=[describe_inner(args[0])],
+                    variadic=True,
+                    min_length=min_length_meta.value if min_length_meta else None,
+                    max_length=max_length_meta.value if max_length_meta else None,
+                )
             if not args or Ellipsis in args:
                 raise RuntimeError("Variable length tuples are not supported")
-            return TupleType(item_types=[describe_type(annotation_wrapper(arg), state) for arg in args])
+            return TupleType(item_types=[describe_inner(arg) for arg in args])
+
+        if issubclass(t, Flag):
+            dump_format_meta = _find_metadata(metadata, EnumDumpFormat)
+            return FlagType(cls=t, dump_format=dump_format_meta.format if dump_format_meta else "value")
 
         if issubclass(t, (Enum, IntEnum)):
-            return EnumType(cls=t)
+            by_name_meta = _find_metadata(metadata, EnumByName)
+            dump_format_meta = _find_metadata(metadata, EnumDumpFormat)
+            return EnumType(
+                cls=t,
+                load_names=by_name_meta is not None,
+                load_values=by_name_meta is None or by_name_meta.values,
+                dump_format=dump_format_meta.format if dump_format_meta else "str",
+            )
+
+        # a model class can only exist once pydantic is imported, v1 models have no model_validate
+        if (
+            (pydantic := sys.modules.get("pydantic"))
+            and issubclass(t, pydantic.BaseModel)
+            and hasattr(t, "model_validate")
+        ):
+            return PydanticModelType(cls=t)
 
         if dataclasses.is_dataclass(t):
             state[(t, filed_format)] = None
-            entity_type = _describe_dataclass(t, generics, filed_format, state)
+            entity_type = _describe_dataclass(t, generics, filed_format, state, fallback_encoder_factory)
             state[(t, filed_format)] = entity_type
             return entity_type
 
         if attr and attr.has(t):
             state[(t, filed_format)] = None
-            entity_type = _describe_attrs(t, generics, filed_format, state)
+            entity_type = _describe_attrs(t, generics, filed_format, state, fallback_encoder_factory)
             state[(t, filed_format)] = entity_type
             return entity_type
 
@@ -274,11 +663,14 @@ def describe_type(t: Any, state: Optional[dict[tuple[type, FiledFormat], Optiona
         if len(args) != 2 or _NoneType not in args:
             raise RuntimeError(f"Only Unions of one type with None are supported: {t}, {args}")
         inner = args[1] if args[0] is _NoneType else args[0]
-        return OptionalType(describe_type(annotation_wrapper(inner), state))
+        return OptionalType(describe_inner(inner))
 
     if isinstance(t, TypeVar):
         raise RuntimeError(f"Unfilled TypeVar: {t}")
 
+    if fallback_encoder_factory and (encoder := fallback_encoder_factory(t)) is not None:
+        return CustomType(cls=t, encoder=encoder)
+
     raise RuntimeError(f"Unknown type {t!r}")
 
 
@@ -287,6 +679,7 @@ def _describe_dataclass(
     generics: Mapping[TypeVar, Any],
     cls_filed_format: FiledFormat,
     state: dict[tuple[type, FiledFormat], Optional[Type]],
+    fallback_encoder_factory: Optional[FallbackEncoderFactory],
 ) -> EntityType:
     docs = get_attributes_doc(t)
     try:
@@ -302,8 +695,13 @@ def _describe_dataclass(
             type_ = Annotated[type_, cls_filed_format]
 
         metadata = _get_annotated_metadata(type_)
-        field_type = describe_type(type_, state)
+        field_type = describe_type(type_, state, fallback_encoder_factory)
         field_format = _find_metadata(metadata, FiledFormat)
+        error_message = _find_metadata(metadata, ErrorMessage)
+        aliases = _find_metadata(metadata, Aliases)
+        skip_if = _find_metadata(metadata, SkipIf)
+        load_key = _find_metadata(metadata, LoadKey)
+        dump_key = _find_metadata(metadata, DumpKey)
 
         fields.append(
             EntityField(
@@ -316,8 +714,18 @@ def _describe_dataclass(
                     field.default_factory if field.default_factory is not dataclasses.MISSING else NOT_SET
                 ),
                 is_property=False,
+                is_extra_keys=_find_metadata(metadata, ExtraKeys) is not None,
+                init=field.init,
+                slot=_slot_descriptor(t, field.name),
+                error_message=error_message.template if error_message else None,
+                aliases=list(aliases.keys) if aliases else [],
+                skip_if_default=_find_metadata(metadata, SkipIfDefault) is not None,
+                skip_if=skip_if.predicate if skip_if else None,
+                load_key=load_key.key if load_key else None,
+                dump_key=dump_key.key if dump_key else None,
             )
         )
+    fields.extend(_describe_computed_fields(t, generics, cls_filed_format, state, fallback_encoder_factory))
 
     return EntityType(cls=t, name=_generate_name(t, cls_filed_format), fields=fields, generics=generics, doc=t.__doc__)
 
@@ -327,6 +735,7 @@ def _describe_attrs(
     generics: Mapping[TypeVar, Any],
     cls_filed_format: FiledFormat,
     state: dict[tuple[type, FiledFormat], Optional[Type]],
+    fallback_encoder_factory: Optional[FallbackEncoderFactory],
 ) -> EntityType:
     assert attr is not None
     docs = get_attributes_doc(t)
@@ -345,12 +754,21 @@ def _describe_attrs(
             default_factory = field.default.factory
 
         type_ = _replace_generics(types.get(field.name, field.type), generics)
+        if field_metadata := field.metadata.get(ATTRS_METADATA_KEY):
+            type_ = Annotated[(type_, *field_metadata)]
         if cls_filed_format:
             type_ = Annotated[type_, cls_filed_format]
 
         metadata = _get_annotated_metadata(type_)
-        field_type = describe_type(type_, state)
+        field_type = describe_type(type_, state, fallback_encoder_factory)
         field_format = _find_metadata(metadata, FiledFormat)
+        error_message = _find_metadata(metadata, ErrorMessage)
+        aliases = _find_metadata(metadata, Aliases)
+        skip_if = _find_metadata(metadata, SkipIf)
+        load_key = _find_metadata(metadata, LoadKey)
+        dump_key = _find_metadata(metadata, DumpKey)
+        # attrs strips the leading underscore of private attributes in __init__ arguments
+        init_name = getattr(field, "alias", None) or field.name.lstrip("_")
 
         fields.append(
             EntityField(
@@ -361,11 +779,78 @@ def _describe_attrs(
                 default=default,
                 default_factory=default_factory,
                 is_property=False,
+                is_extra_keys=_find_metadata(metadata, ExtraKeys) is not None,
+                init=field.init,
+                init_name=init_name if init_name != field.name else None,
+                slot=_slot_descriptor(t, field.name),
+                error_message=error_message.template if error_message else None,
+                aliases=list(aliases.keys) if aliases else [],
+                skip_if_default=_find_metadata(metadata, SkipIfDefault) is not None,
+                skip_if=skip_if.predicate if skip_if else None,
+                load_key=load_key.key if load_key else None,
+                dump_key=dump_key.key if dump_key else None,
             )
         )
+    fields.extend(_describe_computed_fields(t, generics, cls_filed_format, state, fallback_encoder_factory))
     return EntityType(cls=t, name=_generate_name(t, cls_filed_format), fields=fields, generics=generics)
 
 
+def _describe_computed_fields(
+    t: type[Any],
+    generics: Mapping[TypeVar, Any],
+    cls_filed_format: FiledFormat,
+    state: dict[tuple[type, FiledFormat], Optional[Type]],
+    fallback_encoder_factory: Optional[FallbackEncoderFactory],
+) -> list[EntityField]:
+    members = {name: member for klass in reversed(t.__mro__) for name, member in vars(klass).items()}
+    fields = []
+    for name, member in members.items():
+        if not isinstance(member, property) or "return" not in getattr(member.fget, "__annotations__", {}):
+            continue
+        return_type = get_type_hints(member.fget, include_extras=True)["return"]
+        if _find_metadata(_get_annotated_metadata(return_type), Computed) is None:
+            continue
+        type_ = _replace_generics(return_type, generics)
+        if cls_filed_format:
+            type_ = Annotated[type_, cls_filed_format]
+        field_format = _find_metadata(_get_annotated_metadata(type_), FiledFormat)
+        fields.append(
+            EntityField(
+                name=name,
+                dict_key=_apply_format(field_format, name),
+                doc=member.__doc__,
+                type=describe_type(type_, state, fallback_encoder_factory),
+                is_property=True,
+            )
+        )
+    return fields
+
+
+def iter_types(t: Type) -> Iterator[Type]:
+    """
+    Yields the type and all nested types, each entity once.
+    """
+    seen: set[int] = set()
+    stack = [t]
+    while stack:
+        item = stack.pop()
+        if id(item) in seen:
+            continue
+        seen.add(id(item))
+        yield item
+        for value in vars(item).values():
+            for v in value if isinstance(value, list) else [value]:
+                if isinstance(v, EntityField):
+                    stack.append(v.type)
+                elif isinstance(v, Type):
+                    stack.append(v)
+
+
+def _slot_descriptor(t: type[Any], name: str) -> Optional[MemberDescriptorType]:
+    descriptor = inspect.getattr_static(t, name, None)
+    return descriptor if isinstance(descriptor, MemberDescriptorType) else None
+
+
 def _replace_generics(t: Any, generics: Mapping[TypeVar, Any]) -> Any:
     try:
         if parameters := getattr(t, "__parameters__", None):
@@ -1 +1,17 @@
-from ._serpyco_rs import Serializer, ValidationError, make_encoder  # pyright: ignore
+from ._serpyco_rs import (  # pyright: ignore
+    CancellationToken,
+    ConstraintError,
+    LoadCancelled,
+    LoadTimeout,
+    RequiredFieldError,
+    UNSET,
+    Serializer,
+    TypeMismatchError,
+    Unset,
+    ValidationError,
+    dump_duration,
+    make_encoder,
+    parse_duration,
+    presize_stats,
+    self_check,
+)
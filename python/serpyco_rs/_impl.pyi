@@ -1,17 +1,65 @@
-from typing import Any, Generic, TypeVar
+from datetime import timedelta
+from collections.abc import Iterable
+from typing import Any, Generic, Optional, TypeVar, Union
 
 from ._describe import Type
 
 _T = TypeVar("_T")
 
 class ValidationError(Exception):
+    # keys and indexes of the value that failed in Serializer.load, not set on other errors
+    path: tuple[Union[str, int], ...]
+
+class RequiredFieldError(ValidationError):
+    pass
+
+class TypeMismatchError(ValidationError):
     pass
 
+class ConstraintError(ValidationError):
+    pass
+
+class LoadCancelled(Exception):
+    pass
+
+class LoadTimeout(LoadCancelled):
+    path: str
+
+class Unset:
+    pass
+
+UNSET: Unset
+
+class CancellationToken:
+    def cancel(self) -> None:
+        pass
+    @property
+    def cancelled(self) -> bool:
+        pass
+
 class Serializer(Generic[_T]):
-    def dump(self, value: _T) -> Any:
+    def dump(self, value: _T, include: Optional[Iterable[str]] = None, exclude: Optional[Iterable[str]] = None) -> Any:
         pass
-    def load(self, data: Any) -> _T:
+    def load(
+        self,
+        data: Any,
+        trusted: bool = False,
+        cancel_token: Optional[CancellationToken] = None,
+        max_duration_ms: Optional[int] = None,
+        partial: bool = False,
+    ) -> _T:
         pass
 
 def make_encoder(py_class: Type) -> Serializer[_T]:
     pass
+def parse_duration(value: str) -> timedelta:
+    pass
+
+def dump_duration(value: timedelta) -> str:
+    pass
+
+def self_check() -> dict[str, Optional[str]]:
+    pass
+
+def presize_stats() -> dict[str, int]:
+    pass
@@ -2,7 +2,21 @@ from functools import singledispatch
 from typing import Any, Optional
 
 from .. import _describe as describe
-from ._entities import ArrayType, Boolean, IntegerType, Null, NumberType, ObjectType, RefType, Schema, StringType
+from .._impl import make_encoder
+from ..metadata import BytesEncoding, DurationFormat, UUIDFormat
+from ._entities import (
+    ArrayType,
+    Boolean,
+    IntegerType,
+    ModelSchema,
+    Null,
+    NumberType,
+    ObjectType,
+    RefType,
+    RequiredKeys,
+    Schema,
+    StringType,
+)
 
 
 def get_json_schema(t: describe.Type) -> dict[str, Any]:
@@ -16,13 +30,25 @@ def get_json_schema(t: describe.Type) -> dict[str, Any]:
     }
 
 
+def to_json_schema(t: describe.Type, doc: Optional[str] = None) -> Schema:
+    schema = _to_json_schema(t, doc)
+    if t.examples is not None:
+        # examples are written in the dumped form, like datetimes as strings
+        encoder = make_encoder(t)
+        schema.examples = [encoder.dump(value) for value in t.examples]
+    return schema
+
+
 @singledispatch
-def to_json_schema(_: Any, doc: Optional[str] = None) -> Schema:
+def _to_json_schema(_: Any, doc: Optional[str] = None) -> Schema:
     return Schema(description=doc)
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(arg: describe.StringType, doc: Optional[str] = None) -> Schema:
+    if arg.strip_whitespace:
+        # lengths are checked after stripping on load
+        return StringType(description=doc)
     return StringType(
         minLength=arg.min_length,
         maxLength=arg.max_length,
@@ -30,33 +56,68 @@ def _(arg: describe.StringType, doc: Optional[str] = None) -> Schema:
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(arg: describe.IntegerType, doc: Optional[str] = None) -> Schema:
-    return IntegerType(
+    schema = IntegerType(
         minimum=arg.min,
         maximum=arg.max,
+        exclusiveMinimum=arg.exclusive_min,
+        exclusiveMaximum=arg.exclusive_max,
+        multipleOf=arg.multiple_of,
+    )
+    if not arg.prefixed:
+        schema.description = doc
+        return schema
+    # bounds of string values are checked after conversion
+    return Schema(
+        anyOf=[schema, StringType(pattern=r"^[+-]?(0[xX][0-9a-fA-F_]+|0[oO][0-7_]+|0[bB][01_]+|[0-9_]+)$")],
         description=doc,
     )
 
 
-@to_json_schema.register
-def _(_: describe.BytesType, doc: Optional[str] = None) -> Schema:
+_BYTES_PATTERNS = {
+    BytesEncoding.base64_nopad: r"^[A-Za-z0-9+/]*$",
+    BytesEncoding.base64url: r"^([A-Za-z0-9_-]{4})*([A-Za-z0-9_-]{2}==|[A-Za-z0-9_-]{3}=)?$",
+    BytesEncoding.base64url_nopad: r"^[A-Za-z0-9_-]*$",
+    BytesEncoding.base32: r"^([A-Z2-7]{8})*([A-Z2-7]{2}={6}|[A-Z2-7]{4}={4}|[A-Z2-7]{5}={3}|[A-Z2-7]{7}=)?$",
+    BytesEncoding.base32_nopad: r"^[A-Z2-7]*$",
+    BytesEncoding.base58: r"^[1-9A-HJ-NP-Za-km-z]*$",
+    BytesEncoding.hex: r"^([0-9a-fA-F]{2})*$",
+}
+
+
+@_to_json_schema.register
+def _(arg: describe.BytesType, doc: Optional[str] = None) -> Schema:
+    if arg.encoding is BytesEncoding.base64:
+        return StringType(
+            format="byte",
+            pattern=r"^([A-Za-z0-9+/]{4})*([A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$",
+            description=doc,
+        )
+    if pattern := _BYTES_PATTERNS.get(arg.encoding):
+        return StringType(
+            pattern=pattern,
+            description=doc,
+        )
     return StringType(
         format="binary",
         description=doc,
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(arg: describe.FloatType, doc: Optional[str] = None) -> Schema:
     return NumberType(
         minimum=arg.min,
         maximum=arg.max,
+        exclusiveMinimum=arg.exclusive_min,
+        exclusiveMaximum=arg.exclusive_max,
+        multipleOf=arg.multiple_of,
         description=doc,
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(_: describe.DecimalType, doc: Optional[str] = None) -> Schema:
     return Schema(
         oneOf=[
@@ -67,20 +128,73 @@ def _(_: describe.DecimalType, doc: Optional[str] = None) -> Schema:
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(_: describe.BooleanType, doc: Optional[str] = None) -> Schema:
     return Boolean()
 
 
-@to_json_schema.register
-def _(_: describe.UUIDType, doc: Optional[str] = None) -> Schema:
-    return StringType(
-        format="uuid",
+@_to_json_schema.register
+def _(arg: describe.UUIDType, doc: Optional[str] = None) -> Schema:
+    schema = StringType(
+        # non-canonical forms are checked on load
+        format="uuid" if not arg.as_str and arg.dump_format is UUIDFormat.canonical else None,
         description=doc,
     )
+    if arg.lenient:
+        # the 128-bit upper bound is checked on load, jsonschema_rs can't hold it in the schema
+        return Schema(anyOf=[schema, IntegerType(minimum=0)], description=doc)
+    return schema
+
+
+@_to_json_schema.register
+def _(arg: describe.IPAddressType, doc: Optional[str] = None) -> Schema:
+    return StringType(format=f"ipv{arg.version}", description=doc)
+
 
+@_to_json_schema.register
+def _(_: describe.IPNetworkType, doc: Optional[str] = None) -> Schema:
+    return StringType(description=doc)
 
-@to_json_schema.register
+
+@_to_json_schema.register
+def _(_: describe.IPInterfaceType, doc: Optional[str] = None) -> Schema:
+    return StringType(description=doc)
+
+
+@_to_json_schema.register
+def _(_: describe.MoneyType, doc: Optional[str] = None) -> Schema:
+    return Schema(
+        anyOf=[
+            ObjectType(
+                properties={
+                    "amount": Schema(oneOf=[StringType(), NumberType()]),
+                    "currency": StringType(pattern=r"^[A-Z]{3}$"),
+                },
+                required=["amount", "currency"],
+            ),
+            StringType(pattern=r"^\S+ [A-Z]{3}$"),
+        ],
+        description=doc,
+    )
+
+
+@_to_json_schema.register
+def _(_: describe.SecretType, doc: Optional[str] = None) -> Schema:
+    # validation errors would include the secret value, the type is checked on load instead
+    return Schema(description=doc)
+
+
+@_to_json_schema.register
+def _(_: describe.SemVerType, doc: Optional[str] = None) -> Schema:
+    return StringType(pattern=describe.SEMVER_PATTERN, description=doc)
+
+
+@_to_json_schema.register
+def _(_: describe.PathType, doc: Optional[str] = None) -> Schema:
+    return StringType(description=doc)
+
+
+@_to_json_schema.register
 def _(_: describe.TimeType, doc: Optional[str] = None) -> Schema:
     iso8601_pattern = (
         r"^[0-9][0-9]:[0-9][0-9](:[0-9][0-9](\.[0-9]+)?)?"  # HH:mm:ss.ssss
@@ -93,39 +207,98 @@ def _(_: describe.TimeType, doc: Optional[str] = None) -> Schema:
     )
 
 
-@to_json_schema.register
-def _(_: describe.DateTimeType, doc: Optional[str] = None) -> Schema:
+@_to_json_schema.register
+def _(arg: describe.DateTimeType, doc: Optional[str] = None) -> Schema:
+    schemas = [_datetime_format_schema(fmt) for fmt in arg.formats]
+    if len(schemas) == 1:
+        schemas[0].description = doc
+        return schemas[0]
+    return Schema(anyOf=schemas, description=doc)
+
+
+def _datetime_format_schema(fmt: str) -> Schema:
+    if fmt in {"epoch", "epoch_ms"}:
+        return NumberType()
+    if fmt != "iso":
+        return StringType()
     iso8601_pattern = (
-        r"^[0-9]{4}-[0-9][0-9]-[0-9][0-9]T"  # YYYY-MM-DD
+        r"^[+-]?[0-9]{4,}-[0-9][0-9]-[0-9][0-9]T"  # YYYY-MM-DD
         r"[0-9][0-9]:[0-9][0-9]:[0-9][0-9](\.[0-9]+)"  # HH:mm:ss.ssss
         r"?(([+-][0-9][0-9]:[0-9][0-9])|Z)?$"  # timezone
     )
+    # no format="regex", a leading "+" is not a valid regex
+    return StringType(
+        pattern=iso8601_pattern,
+    )
+
+
+@_to_json_schema.register
+def _(arg: describe.DateType, doc: Optional[str] = None) -> Schema:
+    # signed and 5+ digit years pass, the encoder clamps them or reports them as out of range
+    iso8601_pattern = r"^[+-]?[0-9]{4,}-[0-9][0-9]-[0-9][0-9]$"  # YYYY-MM-DD
+    if arg.lenient:
+        iso8601_pattern = (
+            r"^[+-]?[0-9]{4,}-("
+            r"[0-9][0-9]-[0-9][0-9]"  # YYYY-MM-DD
+            r"|W[0-9][0-9]-[1-7]"  # YYYY-Www-D
+            r"|[0-9]{3}"  # YYYY-DDD
+            r")$"
+        )
+    # no format="regex", a leading "+" is not a valid regex
     return StringType(
-        format="regex",
         pattern=iso8601_pattern,
         description=doc,
     )
 
 
-@to_json_schema.register
-def _(_: describe.DateType, doc: Optional[str] = None) -> Schema:
-    iso8601_pattern = r"^[0-9]{4}-[0-9][0-9]-[0-9][0-9]$"  # YYYY-MM-DD
+@_to_json_schema.register
+def _(arg: describe.TimeDurationType, doc: Optional[str] = None) -> Schema:
+    if arg.format is DurationFormat.seconds:
+        return NumberType(description=doc)
+    iso8601_pattern = (
+        r"^[-+]?P([0-9]+W)?([0-9]+D)?"  # PnWnD
+        r"(T([0-9]+H)?([0-9]+M)?([0-9]+([.,][0-9]+)?S)?)?$"  # TnHnMn.nS
+    )
+    # no format="regex", a leading "+" is not a valid regex
     return StringType(
-        format="regex",
         pattern=iso8601_pattern,
         description=doc,
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
+def _(arg: describe.JsonStringType, doc: Optional[str] = None) -> Schema:
+    # contentSchema is an annotation only, inner value is checked by the encoder
+    return StringType(
+        contentMediaType="application/json",
+        contentSchema=to_json_schema(arg.inner),
+        description=doc,
+    )
+
+
+@_to_json_schema.register
+def _(arg: describe.FlagType, doc: Optional[str] = None) -> Schema:
+    return Schema(
+        anyOf=[
+            IntegerType(minimum=0),
+            ArrayType(items=Schema(enum=list(arg.cls.__members__))),
+        ],
+        description=doc,
+    )
+
+
+@_to_json_schema.register
 def _(arg: describe.EnumType, doc: Optional[str] = None) -> Schema:
     return Schema(
-        enum=[item.value for item in arg.cls],
+        enum=[
+            *([item.name for item in arg.cls] if arg.load_names else []),
+            *([item.value for item in arg.cls] if arg.load_values else []),
+        ],
         description=doc,
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(arg: describe.OptionalType, doc: Optional[str] = None) -> Schema:
     return Schema(
         anyOf=[
@@ -136,30 +309,67 @@ def _(arg: describe.OptionalType, doc: Optional[str] = None) -> Schema:
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(arg: describe.EntityType, doc: Optional[str] = None) -> Schema:
+    required = [
+        prop
+        for prop in arg.fields
+        if not (
+            prop.is_property
+            or prop.is_extra_keys
+            or (arg.use_init and not prop.init)
+            or prop.default != describe.NOT_SET
+            or prop.default_factory != describe.NOT_SET
+        )
+    ]
     return ObjectType(
-        properties={prop.dict_key: to_json_schema(prop.type, prop.doc) for prop in arg.fields if not prop.is_property},
-        required=[
-            prop.dict_key
-            for prop in arg.fields
-            if not (prop.is_property or prop.default != describe.NOT_SET or prop.default_factory != describe.NOT_SET)
+        properties={
+            **{
+                prop.load_key or prop.dict_key: to_json_schema(prop.type, prop.doc)
+                for prop in arg.fields
+                if not (prop.is_property or prop.is_extra_keys)
+            },
+            **{
+                alias: Schema(allOf=[to_json_schema(prop.type, prop.doc)], deprecated=True)
+                for prop in arg.fields
+                for alias in prop.aliases
+            },
+        },
+        required=[prop.load_key or prop.dict_key for prop in required if not prop.aliases] or None,
+        # a field with aliases is required by any of its keys
+        allOf=[
+            Schema(anyOf=[RequiredKeys(required=[key]) for key in (prop.load_key or prop.dict_key, *prop.aliases)])
+            for prop in required
+            if prop.aliases
         ]
         or None,
+        additionalProperties=next(
+            (to_json_schema(prop.type.value_type) for prop in arg.fields if prop.is_extra_keys), None
+        ),
         name=arg.name,
         description=arg.doc,
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(arg: describe.ArrayType, doc: Optional[str] = None) -> Schema:
     return ArrayType(
         items=to_json_schema(arg.item_type),
+        uniqueItems=arg.unique_items or None,
+        description=doc,
+    )
+
+
+@_to_json_schema.register
+def _(arg: describe.SetType, doc: Optional[str] = None) -> Schema:
+    return ArrayType(
+        items=to_json_schema(arg.item_type),
+        uniqueItems=arg.unique_items or None,
         description=doc,
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(arg: describe.DictionaryType, doc: Optional[str] = None) -> Schema:
     return ObjectType(
         additionalProperties=to_json_schema(arg.value_type),
@@ -167,8 +377,15 @@ def _(arg: describe.DictionaryType, doc: Optional[str] = None) -> Schema:
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(arg: describe.TupleType, doc: Optional[str] = None) -> Schema:
+    if arg.variadic:
+        return ArrayType(
+            items=to_json_schema(arg.item_types[0]),
+            minItems=arg.min_length,
+            maxItems=arg.max_length,
+            description=doc,
+        )
     return ArrayType(
         prefixItems=[to_json_schema(item) for item in arg.item_types],
         minItems=len(arg.item_types),
@@ -177,11 +394,25 @@ def _(arg: describe.TupleType, doc: Optional[str] = None) -> Schema:
     )
 
 
-@to_json_schema.register
+@_to_json_schema.register
 def _(_: describe.AnyType, doc: Optional[str] = None) -> Schema:
     return Schema(description=doc)
 
 
-@to_json_schema.register
+@_to_json_schema.register
+def _(_: describe.CustomType, doc: Optional[str] = None) -> Schema:
+    return Schema(description=doc)
+
+
+@_to_json_schema.register
+def _(arg: describe.PydanticModelType, doc: Optional[str] = None) -> Schema:
+    return ModelSchema(
+        name=arg.cls.__name__,
+        description=doc,
+        schema=arg.cls.model_json_schema(ref_template="#/definitions/{model}"),
+    )
+
+
+@_to_json_schema.register
 def _(holder: describe.RecursionHolder, doc: Optional[str] = None) -> Schema:
     return RefType(description=doc, ref=f"#/definitions/{holder.name}")
@@ -12,6 +12,7 @@ class Schema:
     default: Any | None = None
     deprecated: bool | None = None
     enum: list[Any] | None = None
+    examples: list[Any] | None = None
 
     allOf: list[Schema] | None = None
     anyOf: list[Schema] | None = None
@@ -25,6 +26,7 @@ class Schema:
             "default": self.default,
             "deprecated": self.deprecated,
             "enum": self.enum,
+            "examples": self.examples,
             "allOf": [item.dump(definitions) for item in self.allOf] if self.allOf else None,
             "anyOf": [item.dump(definitions) for item in self.anyOf] if self.anyOf else None,
             "oneOf": [item.dump(definitions) for item in self.oneOf] if self.oneOf else None,
@@ -49,6 +51,8 @@ class StringType(Schema):
     maxLength: int | None = None
     pattern: str | None = None
     format: str | None = None
+    contentMediaType: str | None = None
+    contentSchema: Schema | None = None
     # todo: enum https://json-schema.org/understanding-json-schema/reference/string.html#built-in-formats
 
     def dump(self, definitions: dict[str, Any]) -> dict[str, Any]:
@@ -58,6 +62,8 @@ class StringType(Schema):
             "maxLength": self.maxLength,
             "pattern": self.pattern,
             "format": self.format,
+            "contentMediaType": self.contentMediaType,
+            "contentSchema": self.contentSchema.dump(definitions) if self.contentSchema else None,
             **data,
         }
         return {k: v for k, v in data.items() if v is not None}
@@ -66,9 +72,11 @@ class StringType(Schema):
 @dataclass
 class NumberType(Schema):
     type: str = "number"
-    multipleOf: int | None = None
+    multipleOf: float | None = None
     minimum: float | None = None
     maximum: float | None = None
+    exclusiveMinimum: float | None = None
+    exclusiveMaximum: float | None = None
 
     def dump(self, definitions: dict[str, Any]) -> dict[str, Any]:
         data = super().dump(definitions)
@@ -76,6 +84,8 @@ class NumberType(Schema):
             "multipleOf": self.multipleOf,
             "minimum": self.minimum,
             "maximum": self.maximum,
+            "exclusiveMinimum": self.exclusiveMinimum,
+            "exclusiveMaximum": self.exclusiveMaximum,
             **data,
         }
         return {k: v for k, v in data.items() if v is not None}
@@ -115,6 +125,19 @@ class ObjectType(Schema):
         }
 
 
+@dataclass
+class RequiredKeys(Schema):
+    """
+    Subschema that only lists required keys, like the branches of a field with aliases.
+    """
+
+    required: list[str] | None = None
+
+    def dump(self, definitions: dict[str, Any]) -> dict[str, Any]:
+        data = super().dump(definitions)
+        return {k: v for k, v in {"required": self.required, **data}.items() if v is not None}
+
+
 @dataclass
 class ArrayType(Schema):
     type: str = "array"
@@ -137,6 +160,25 @@ class ArrayType(Schema):
         return {k: v for k, v in data.items() if v is not None}
 
 
+@dataclass
+class ModelSchema(Schema):
+    """
+    Schema generated by a pydantic model, stored in the definitions with its "$defs".
+    """
+
+    name: str | None = None
+    schema: dict[str, Any] | None = None
+
+    def dump(self, definitions: dict[str, Any]) -> dict[str, Any]:
+        data = dict(self.schema or {})
+        definitions.update(data.pop("$defs", {}))
+        definitions[self.name] = data
+        return {
+            "$ref": f"#/definitions/{self.name}",
+            **super().dump(definitions),
+        }
+
+
 @dataclass
 class RefType(Schema):
     ref: str | None = None
@@ -1,9 +1,19 @@
+import operator
+import reprlib
+import sys
 from abc import ABCMeta, abstractmethod
-from typing import Any
+from collections.abc import Mapping
+from fractions import Fraction
+from itertools import islice
+from typing import Any, Optional, Union, cast
 
 import jsonschema_rs  # type: ignore
 from serpyco_rs.exceptions import ErrorItem, SchemaValidationError
 
+_value_repr = reprlib.Repr()
+_value_repr.maxstring = 80
+_value_repr.maxother = 80
+
 
 class Validator(metaclass=ABCMeta):
     @abstractmethod
@@ -14,20 +24,236 @@ class Validator(metaclass=ABCMeta):
     def validate(self, data: Any) -> None:
         ...
 
+    def errors(self, data: Any) -> list[ErrorItem]:
+        try:
+            self.validate(data)
+        except SchemaValidationError as e:
+            return e.errors
+        return []
+
 
 class JsonschemaRSValidator(Validator):
-    def __init__(self, schema: dict[str, Any]) -> None:
-        self._validator = jsonschema_rs.JSONSchema(schema)
+    def __init__(self, schema: dict[str, Any], max_errors: Optional[int] = None) -> None:
+        # jsonschema_rs can't take ints beyond 64 bits, the bounds of big ints are compared by _big_int_errors
+        self._validator = jsonschema_rs.JSONSchema(_big_ints_to_floats(schema, (), {}))
+        self._schema = schema
+        self._max_errors = max_errors
 
     def validate(self, data: Any) -> None:
-        if not self._validator.is_valid(data):
-            errors = list(self._validator.iter_errors(data))
-            if errors:
-                raise SchemaValidationError([self._map_err(e) for e in errors])
+        if errors := self.errors(data):
+            raise SchemaValidationError(errors)
+
+    def errors(self, data: Any) -> list[ErrorItem]:
+        try:
+            if self._validator.is_valid(data):
+                return []
+        except ValueError:
+            return self._big_int_errors(data)
+        return [self._map_err(e) for e in islice(self._validator.iter_errors(data), self._max_errors)]
+
+    def _big_int_errors(self, data: Any) -> list[ErrorItem]:
+        """
+        Validates ints beyond 64 bits as floats, except the numeric keywords of their schemas:
+        a float can't tell 2**70 from 2**70 + 1, those are checked with python ints.
+        """
+        big_ints: dict[tuple[Union[str, int], ...], int] = {}
+        data = _big_ints_to_floats(data, (), big_ints)
+        exact = {path: _check_big_int(self._schema, path, value) for path, value in big_ints.items()}
+        errors = [
+            self._map_err(e)
+            for e in self._validator.iter_errors(data)
+            if exact.get(tuple(e.instance_path)) is None or e.schema_path[-1] not in _BIG_INT_KEYWORDS
+        ]
+        errors.extend(error for path_errors in exact.values() for error in path_errors or ())
+        return errors[: self._max_errors]
 
     def _map_err(self, err: jsonschema_rs.ValidationError) -> ErrorItem:
+        instance_path = "/".join(map(str, err.instance_path))
+        # objects and arrays may hold Secret fields, only scalars are shown
+        value = None if isinstance(err.instance, (Mapping, list)) else _value_repr.repr(err.instance)
+        expected = _expected_type(self._schema, err.instance_path)
         return ErrorItem(
             message=err.message,
-            instance_path="/".join(map(str, err.instance_path)),
+            instance_path=instance_path,
             schema_path="/".join(map(str, err.schema_path)),
+            path=tuple(err.instance_path),
+            value=value,
+            expected=expected,
         )
+
+
+_MAX_FLOAT = int(sys.float_info.max)
+
+
+def _big_ints_to_floats(data: Any, path: tuple[Union[str, int], ...], big_ints: dict[Any, int]) -> Any:
+    if isinstance(data, int) and not -(2**63) <= data < 2**64:
+        big_ints[path] = data
+        # ints beyond the float range are clamped, _check_big_int compares the exact values
+        return float(data) if abs(data) <= _MAX_FLOAT else (sys.float_info.max if data > 0 else -sys.float_info.max)
+    if isinstance(data, Mapping):
+        return {k: _big_ints_to_floats(v, (*path, k), big_ints) for k, v in data.items()}
+    if isinstance(data, (list, tuple)):
+        return [_big_ints_to_floats(v, (*path, i), big_ints) for i, v in enumerate(data)]
+    return data
+
+
+# jsonschema_rs errors of big ints with these keywords are replaced by the ones of _check_big_int
+_BIG_INT_KEYWORDS = ("minimum", "maximum", "exclusiveMinimum", "exclusiveMaximum", "multipleOf", "anyOf")
+_BOUNDS = (
+    ("minimum", operator.lt, "less than the minimum of"),
+    ("maximum", operator.gt, "greater than the maximum of"),
+    ("exclusiveMinimum", operator.le, "less than or equal to the minimum of"),
+    ("exclusiveMaximum", operator.ge, "greater than or equal to the maximum of"),
+)
+
+
+def _check_big_int(schema: dict[str, Any], path: tuple[Union[str, int], ...], value: int) -> Optional[list[ErrorItem]]:
+    """
+    Checks the numeric keywords of the schema at the path with python ints.
+    Returns None if the schema doesn't take ints, the errors of jsonschema_rs are kept then.
+    """
+    node, schema_path = _locate(schema, path)
+    if node is None:
+        return None
+    is_union = "anyOf" in node
+    branches = [_resolve(schema, branch) for branch in node["anyOf"]] if is_union else [node]
+    errors = [
+        _numeric_error(branch, value)
+        for branch in branches
+        if isinstance(branch, dict) and branch.get("type") in ("integer", "number")
+    ]
+    if not errors:
+        return None
+    if None in errors:
+        return []
+    if is_union:
+        keyword, message = "anyOf", f"{value} is not valid under any of the given schemas"
+    else:
+        keyword, message = cast(tuple[str, str], errors[0])
+    return [
+        ErrorItem(
+            message=message,
+            instance_path="/".join(map(str, path)),
+            schema_path="/".join([*schema_path, keyword]),
+            path=path,
+            value=_value_repr.repr(value),
+            expected=_expected_type(schema, list(path)),
+        )
+    ]
+
+
+def _numeric_error(node: dict[str, Any], value: int) -> Optional[tuple[str, str]]:
+    for keyword, fails, message in _BOUNDS:
+        bound = node.get(keyword)
+        if bound is not None and fails(value, bound):
+            return keyword, f"{value} is {message} {bound}"
+    multiple_of = node.get("multipleOf")
+    # int % float converts the int to a float, which overflows beyond the float range
+    if multiple_of is not None and Fraction(value) % Fraction(multiple_of) != 0:
+        return "multipleOf", f"{value} is not a multiple of {multiple_of}"
+    return None
+
+
+def _locate(
+    schema: dict[str, Any], instance_path: tuple[Union[str, int], ...]
+) -> tuple[Optional[dict[str, Any]], list[str]]:
+    """
+    Same as _schema_at, also returns the schema path of the node like jsonschema_rs reports it.
+    """
+    node, schema_path = _resolve_with_path(schema, schema, [])
+    for part in instance_path:
+        if not isinstance(node, dict):
+            return None, schema_path
+        if "anyOf" in node:
+            # the branch of the container, like an Optional entity
+            container = "array" if isinstance(part, int) else "object"
+            branches = [
+                _resolve_with_path(schema, branch, [*schema_path, "anyOf", str(i)])
+                for i, branch in enumerate(node["anyOf"])
+            ]
+            node, schema_path = next(
+                ((b, p) for b, p in branches if isinstance(b, dict) and b.get("type") == container), (None, schema_path)
+            )
+            if node is None:
+                return None, schema_path
+        if isinstance(part, int):
+            prefix_items = node.get("prefixItems") or []
+            if part < len(prefix_items):
+                child, keys = prefix_items[part], ["prefixItems", str(part)]
+            else:
+                child, keys = node.get("items"), ["items"]
+        elif part in node.get("properties", {}):
+            child, keys = node["properties"][part], ["properties", part]
+        else:
+            child, keys = node.get("additionalProperties"), ["additionalProperties"]
+        node, schema_path = _resolve_with_path(schema, child, [*schema_path, *keys])
+    return (node if isinstance(node, dict) else None), schema_path
+
+
+def _resolve_with_path(schema: dict[str, Any], node: Any, schema_path: list[str]) -> tuple[Any, list[str]]:
+    """
+    Same as _resolve, also follows the schema path. It restarts at a $ref, like the ones jsonschema_rs reports.
+    """
+    while isinstance(node, dict):
+        if "$ref" in node:
+            node, schema_path = _resolve_ref(schema, node["$ref"]), []
+        elif len(node.get("allOf") or ()) == 1 and "type" not in node:
+            node, schema_path = node["allOf"][0], [*schema_path, "allOf", "0"]
+        else:
+            break
+    return node, schema_path
+
+
+def _expected_type(schema: dict[str, Any], instance_path: list[Any]) -> Union[str, list[str], None]:
+    """
+    Returns the type of the schema at the instance path, the types of the branches for anyOf.
+    """
+    node = _schema_at(schema, instance_path)
+    if node is None:
+        return None
+    if "anyOf" in node:
+        types = [_resolve(schema, branch).get("type") for branch in node["anyOf"]]
+        return [t for t in types if isinstance(t, str)]
+    return node.get("type")
+
+
+def _schema_at(schema: dict[str, Any], instance_path: list[Any]) -> Optional[dict[str, Any]]:
+    node: Optional[dict[str, Any]] = _resolve(schema, schema)
+    for part in instance_path:
+        if node is None:
+            break
+        node = _child_schema(schema, node, part)
+    return node
+
+
+def _child_schema(schema: dict[str, Any], node: dict[str, Any], part: Union[str, int]) -> Optional[dict[str, Any]]:
+    if "anyOf" in node:
+        children = (_child_schema(schema, _resolve(schema, branch), part) for branch in node["anyOf"])
+        return next((child for child in children if child is not None), None)
+    if isinstance(part, int):
+        prefix_items = node.get("prefixItems") or []
+        child = prefix_items[part] if part < len(prefix_items) else node.get("items")
+    else:
+        child = node.get("properties", {}).get(part, node.get("additionalProperties"))
+    return _resolve(schema, child) if isinstance(child, dict) else None
+
+
+def _resolve(schema: dict[str, Any], node: Any) -> Any:
+    """
+    Follows $refs and single allOf wrappers, like the ones of deprecated aliases.
+    """
+    while isinstance(node, dict):
+        if "$ref" in node:
+            node = _resolve_ref(schema, node["$ref"])
+        elif len(node.get("allOf") or ()) == 1 and "type" not in node:
+            node = node["allOf"][0]
+        else:
+            break
+    return node
+
+
+def _resolve_ref(schema: dict[str, Any], ref: str) -> Any:
+    node: Any = schema
+    for part in ref.removeprefix("#/").split("/"):
+        node = node.get(part) if isinstance(node, dict) else None
+    return node
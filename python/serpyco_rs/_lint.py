@@ -0,0 +1,65 @@
+from collections.abc import Iterator
+from dataclasses import dataclass
+from functools import singledispatch
+from typing import Any
+
+from . import _describe as describe
+
+
+@dataclass
+class LintWarning:
+    message: str
+    instance_path: str
+
+
+def lint(t: describe.Type) -> list[LintWarning]:
+    return list(_lint(t, ""))
+
+
+@singledispatch
+def _lint(_: Any, path: str) -> Iterator[LintWarning]:
+    yield from ()
+
+
+@_lint.register
+def _(arg: describe.OptionalType, path: str) -> Iterator[LintWarning]:
+    yield from _lint(arg.inner, path)
+
+
+@_lint.register
+def _(arg: describe.JsonStringType, path: str) -> Iterator[LintWarning]:
+    yield from _lint(arg.inner, path)
+
+
+@_lint.register
+def _(arg: describe.ArrayType, path: str) -> Iterator[LintWarning]:
+    yield from _lint(arg.item_type, path)
+
+
+@_lint.register
+def _(arg: describe.SetType, path: str) -> Iterator[LintWarning]:
+    yield from _lint(arg.item_type, path)
+
+
+@_lint.register
+def _(arg: describe.DictionaryType, path: str) -> Iterator[LintWarning]:
+    yield from _lint(arg.value_type, path)
+
+
+@_lint.register
+def _(arg: describe.TupleType, path: str) -> Iterator[LintWarning]:
+    for item in arg.item_types:
+        yield from _lint(item, path)
+
+
+@_lint.register
+def _(arg: describe.EntityType, path: str) -> Iterator[LintWarning]:
+    for field in arg.fields:
+        field_path = f"{path}/{field.dict_key}" if path else field.dict_key
+        has_default = field.default is not describe.NOT_SET or field.default_factory is not describe.NOT_SET
+        if isinstance(field.type, describe.OptionalType) and not has_default:
+            yield LintWarning(
+                message=f"Optional field {arg.cls.__name__}.{field.name} without default",
+                instance_path=field_path,
+            )
+        yield from _lint(field.type, field_path)
@@ -1,32 +1,585 @@
-from typing import Annotated, Any, Generic, TypeVar, cast
+import dataclasses
+import json
+import weakref
+from collections.abc import Callable, Iterable, Iterator, Mapping, Sequence
+from datetime import tzinfo
+from typing import Annotated, Any, Generic, Literal, Optional, TypeVar, Union, cast, overload
 
-from ._describe import describe_type
+from ._constraints import check_constraints
+from ._describe import (
+    NOT_SET,
+    ArrayType,
+    BooleanType,
+    BytesType,
+    DateTimeType,
+    DateType,
+    DecimalType,
+    DictionaryType,
+    EntityType,
+    EnumType,
+    FallbackEncoderFactory,
+    FloatType,
+    IntegerType,
+    OptionalType,
+    RecursionHolder,
+    SecretType,
+    SetType,
+    StringType,
+    TimeDurationType,
+    TimeType,
+    TupleType,
+    Type,
+    UUIDType,
+    describe_type,
+    iter_types,
+)
 from ._impl import Serializer as _Serializer
-from ._impl import make_encoder
+from ._impl import UNSET, CancellationToken, make_encoder
 from ._json_schema import JsonschemaRSValidator, Validator, get_json_schema
+from ._lint import LintWarning, lint
+from ._report import LoadReport, collect_report
+from .exceptions import ErrorItem, SchemaValidationError, ValidationError
 from .metadata import CamelCase
 
 _T = TypeVar("_T", bound=Any)
 
 
+class _Default:
+    """
+    Default of the Serializer options that fall back to configure(), None is a valid value of some of them.
+    """
+
+    def __repr__(self) -> str:
+        return "DEFAULT"
+
+
+_DEFAULT: Any = _Default()
+
+
 class Serializer(Generic[_T]):
     def __init__(
         self,
         t: type[_T],
-        camelcase_fields: bool = False,
+        camelcase_fields: bool = _DEFAULT,
         validator_cls: type[Validator] = JsonschemaRSValidator,
+        datetime_format: Optional[str] = _DEFAULT,
+        fallback_encoder_factory: Optional[FallbackEncoderFactory] = None,
+        require_aware_datetimes: bool = _DEFAULT,
+        assume_timezone: Optional[tzinfo] = _DEFAULT,
+        convert_datetimes_to_utc: bool = _DEFAULT,
+        reveal_secrets: bool = _DEFAULT,
+        post_load: Optional[str] = None,
+        pre_dump_hooks: Optional[Mapping[type, Callable[[Any], Any]]] = None,
+        keep_unset: bool = False,
+        use_init: bool = False,
+        max_errors: Optional[int] = None,
+        error_messages: Optional[Mapping[str, str]] = _DEFAULT,
     ) -> None:
+        """
+        datetime_format is the dump format of datetimes without DateTimeDumpFormat or DateTimeFormats.
+        fallback_encoder_factory is called with each unsupported type and returns an object
+        with dump(value) and load(value) methods, or None to fail as usual.
+        require_aware_datetimes, assume_timezone and convert_datetimes_to_utc are load-time timezone
+        policies for all datetimes, same as RequireAware, AssumeTimezone and ConvertToUTC.
+        reveal_secrets dumps secret values instead of the redaction placeholder.
+        post_load is a method name (like "__post_init__") called on each loaded entity that defines it,
+        ValueError raised by it is reported as ValidationError. It isn't called with use_init=True,
+        __init__ already ran (and called __post_init__ of dataclasses).
+        pre_dump_hooks maps entity classes to callables applied to their instances before dump,
+        the returned object is dumped instead.
+        keep_unset loads absent Optional fields without a default as UNSET instead of failing,
+        UNSET attributes aren't dumped, e.g. for PATCH requests.
+        use_init=True loads entities by calling the class with the fields as keyword arguments,
+        so validation and state set up in __init__ run. By default __init__ is bypassed.
+        max_errors caps the errors of a SchemaValidationError and the errors collected by load_batch,
+        so a huge invalid payload doesn't build an error per item. validator_cls must accept it as a keyword.
+        error_messages maps the failed schema keyword (like "type", "required" or "maxLength") to a message template,
+        e.g. for localized errors. Templates are formatted with message, instance_path, value and expected,
+        ErrorMessage of the field takes precedence.
+        The options configure() accepts fall back to its defaults when they aren't passed,
+        passing None overrides a configured default.
+        """
+        if max_errors is not None and max_errors < 1:
+            raise ValueError(f"max_errors must be at least 1, got {max_errors}")
+        camelcase_fields = _option("camelcase_fields", camelcase_fields, False)
+        datetime_format = _option("datetime_format", datetime_format, None)
+        require_aware_datetimes = _option("require_aware_datetimes", require_aware_datetimes, False)
+        assume_timezone = _option("assume_timezone", assume_timezone, None)
+        convert_datetimes_to_utc = _option("convert_datetimes_to_utc", convert_datetimes_to_utc, False)
+        reveal_secrets = _option("reveal_secrets", reveal_secrets, False)
+        error_messages = _option("error_messages", error_messages, None)
         if camelcase_fields:
             t = cast(type[_T], Annotated[t, CamelCase])
-        type_info = describe_type(t)
+        type_info = describe_type(t, fallback_encoder_factory=fallback_encoder_factory)
+        for item in iter_types(type_info):
+            if isinstance(item, DateTimeType):
+                if datetime_format and item.dump_format is None and item.formats == ("iso",):
+                    item.dump_format = datetime_format
+                item.require_aware = item.require_aware or require_aware_datetimes
+                item.assume_timezone = item.assume_timezone or assume_timezone
+                item.convert_to_utc = item.convert_to_utc or convert_datetimes_to_utc
+            if isinstance(item, SecretType) and reveal_secrets:
+                item.redact = False
+            if isinstance(item, EntityType) and post_load and not use_init and hasattr(item.cls, post_load):
+                item.post_load = post_load
+            if isinstance(item, EntityType) and pre_dump_hooks and item.cls in pre_dump_hooks:
+                item.pre_dump = pre_dump_hooks[item.cls]
+            if isinstance(item, EntityType) and use_init:
+                item.use_init = True
+            if isinstance(item, EntityType) and keep_unset:
+                for field in item.fields:
+                    has_default = field.default is not NOT_SET or field.default_factory is not NOT_SET
+                    if isinstance(field.type, OptionalType) and not has_default:
+                        field.default = UNSET
+        check_constraints(type_info)
+        self._type_info = type_info
         self._encoder: _Serializer[_T] = make_encoder(type_info)
         self._schema = get_json_schema(type_info)
-        self._validator = validator_cls(self._schema)
+        self._validator_cls = validator_cls
+        self._validator_options = {} if max_errors is None else {"max_errors": max_errors}
+        self._error_messages = error_messages or {}
+        self._max_errors = max_errors
+        self._validator = self._new_validator(type_info, self._schema)
+        self._partial_validator: Optional[Validator] = None
+        self._item_loader: Optional[tuple[_Serializer[Any], Validator]] = None
+        self._field_loaders: dict[str, tuple[_Serializer[Any], Validator, tuple[str, ...]]] = {}
+
+    def dump(self, value: _T, include: Optional[Iterable[str]] = None, exclude: Optional[Iterable[str]] = None) -> Any:
+        """
+        include and exclude are dict keys of entity fields to dump or to leave out,
+        nested entity fields are addressed with dotted paths like "address.zip".
+        """
+        return self._encoder.dump(value, include, exclude)
+
+    def load(
+        self,
+        data: Any,
+        validate: bool = True,
+        trusted: bool = False,
+        cancel_token: Optional[CancellationToken] = None,
+        max_duration_ms: Optional[int] = None,
+        partial: bool = False,
+        from_attributes: bool = False,
+    ) -> _T:
+        """
+        trusted=True is for data validated before, like internal queue messages:
+        schema validation and bounds, length and item checks are skipped, values are only converted.
+        cancel_token is checked before the load, before each entity and each list, set, tuple, dict and field item,
+        LoadCancelled is raised once it's cancelled, e.g. by a signal.alarm handler.
+        max_duration_ms and signal handlers are checked before each entity and every 16 items, LoadTimeout has
+        the instance path where the load was stopped in path. Schema validation isn't limited.
+        partial=True is for PATCH payloads: required fields aren't checked and absent fields are set to UNSET.
+        from_attributes=True loads entities from objects like ORM rows, fields are read with getattr by name
+        into dicts (dicts are still read by key), which are validated and loaded as usual.
+        """
+        if from_attributes:
+            data = _attributes_to_data(self._type_info, data)
+        if validate and not trusted:
+            validator = self._get_partial_validator() if partial else self._validator
+            validator.validate(data)
+        return self._encoder.load(data, trusted, cancel_token, max_duration_ms, partial)
 
-    def dump(self, value: _T) -> Any:
-        return self._encoder.dump(value)
+    def validate(self, data: Any) -> list[ErrorItem]:
+        """
+        Returns the errors load(data) would raise, an empty list for valid data.
+        Schema errors are collected without raising, the encoder only runs on data that passes the schema.
+        """
+        if errors := self._validator.errors(data):
+            return errors
+        try:
+            self._encoder.load(data)
+        except SchemaValidationError as e:
+            return e.errors
+        except ValidationError as e:
+            return [_encoder_error_item(e)]
+        return []
 
-    def load(self, data: Any, validate: bool = True) -> _T:
-        if validate:
+    def load_with_report(self, data: Any, validate: bool = True) -> tuple[_T, LoadReport]:
+        return self.load(data, validate), collect_report(self._type_info, data)
+
+    def lint(self) -> list[LintWarning]:
+        return lint(self._type_info)
+
+    def iter_load_json(self, data: Union[bytes, str], validate: bool = True) -> Iterator[Any]:
+        """
+        Loads items of a top-level JSON array one at a time.
+        Only one decoded item is alive at once, the raw document is kept in memory.
+        """
+        encoder, validator = self._get_item_loader("iter_load_json")
+        for index, item in enumerate(_iter_json_array(data)):
+            if validate:
+                try:
+                    validator.validate(item)
+                except SchemaValidationError as e:
+                    raise SchemaValidationError(_with_index(index, e)) from None
+            yield encoder.load(item)
+
+    @overload
+    def load_batch(self, data: Any, validate: bool = ..., on_error: Literal["raise", "skip"] = ...) -> list[Any]:
+        ...
+
+    @overload
+    def load_batch(
+        self, data: Any, validate: bool = ..., *, on_error: Literal["collect"]
+    ) -> tuple[list[Any], list[tuple[int, ErrorItem]]]:
+        ...
+
+    def load_batch(
+        self, data: Any, validate: bool = True, on_error: Literal["raise", "skip", "collect"] = "raise"
+    ) -> Union[list[Any], tuple[list[Any], list[tuple[int, ErrorItem]]]]:
+        """
+        Loads list items one by one.
+        on_error="skip" drops invalid items, "collect" also returns (index, ErrorItem) pairs for them.
+        """
+        if on_error not in {"raise", "skip", "collect"}:
+            raise ValueError(f"Unknown on_error mode: {on_error!r}")
+        encoder, validator = self._get_item_loader("load_batch")
+        if validate and not isinstance(data, list):
             self._validator.validate(data)
-        return self._encoder.load(data)
+        items = []
+        errors = []
+        for index, item in enumerate(data):
+            # invalid items past max_errors are still skipped, their errors aren't built
+            collect = on_error == "collect" and (self._max_errors is None or len(errors) < self._max_errors)
+            try:
+                if validate:
+                    validator.validate(item)
+                items.append(encoder.load(item))
+            except SchemaValidationError as e:
+                if on_error == "raise":
+                    raise SchemaValidationError(_with_index(index, e)) from None
+                if collect:
+                    errors.extend((index, err) for err in _with_index(index, e))
+            except ValidationError as e:
+                if on_error == "raise":
+                    raise
+                if collect:
+                    errors.append((index, _encoder_error_item(e, (index,))))
+        if self._max_errors is not None:
+            del errors[self._max_errors :]
+        if on_error == "collect":
+            return items, errors
+        return items
+
+    def validate_field(self, path: str, value: Any) -> Any:
+        """
+        Loads a single field value, path is a dotted chain of attribute names like "address.zip".
+        Errors are raised as SchemaValidationError with paths relative to the whole object.
+        """
+        encoder, validator, keys = self._get_field_loader(path)
+        try:
+            validator.validate(value)
+            return encoder.load(value)
+        except SchemaValidationError as e:
+            raise SchemaValidationError(_with_path(keys, e)) from None
+        except ValidationError as e:
+            raise SchemaValidationError([_encoder_error_item(e, keys)]) from None
+
+    def dump_db_params(
+        self, entities: Iterable[_T], placeholders_order: Sequence[str], uuid_as_str: bool = True
+    ) -> list[tuple[Any, ...]]:
+        """
+        Dumps entities to DB driver parameter tuples, one value per field name in placeholders_order.
+        Scalars like datetime and Decimal are kept as is, enums are replaced by their values,
+        nested structures are dumped the same way as in dump().
+        """
+        if not isinstance(self._type_info, EntityType):
+            raise TypeError("dump_db_params is only supported for entity types")
+        fields = {field.name: field for field in self._type_info.fields}
+        adapters = []
+        for name in placeholders_order:
+            if name not in fields:
+                raise KeyError(f"Unknown field {name!r}")
+            adapters.append((name, _db_param_adapter(fields[name].type, uuid_as_str)))
+        return [tuple(adapt(getattr(entity, name)) for name, adapt in adapters) for entity in entities]
+
+    def _get_field_loader(self, path: str) -> "tuple[_Serializer[Any], Validator, tuple[str, ...]]":
+        if path not in self._field_loaders:
+            field_type: Type = self._type_info
+            keys = []
+            for name in path.split("."):
+                while isinstance(field_type, (OptionalType, RecursionHolder)):
+                    field_type = field_type.inner if isinstance(field_type, OptionalType) else field_type.get_type()
+                field = None
+                if isinstance(field_type, EntityType):
+                    field = next((f for f in field_type.fields if f.name == name and not f.is_property), None)
+                if field is None:
+                    raise KeyError(f"Unknown field {path!r}")
+                field_type = field.type
+                keys.append(field.load_key or field.dict_key)
+            validator = self._new_validator(field_type)
+            self._field_loaders[path] = (make_encoder(field_type), validator, tuple(keys))
+        return self._field_loaders[path]
+
+    def _new_validator(self, type_info: Type, schema: Optional[dict[str, Any]] = None) -> Validator:
+        if schema is None:
+            schema = get_json_schema(type_info)
+        validator = self._validator_cls(schema, **self._validator_options)  # type: ignore[call-arg]
+        has_field_messages = any(
+            isinstance(t, EntityType) and any(f.error_message for f in t.fields) for t in iter_types(type_info)
+        )
+        if has_field_messages or self._error_messages:
+            return _ErrorMessagesValidator(validator, type_info, self._error_messages)
+        return validator
+
+    def _get_partial_validator(self) -> Validator:
+        if self._partial_validator is None:
+            self._partial_validator = self._new_validator(self._type_info, _without_required(self._schema))
+        return self._partial_validator
+
+    def _get_item_loader(self, method: str) -> "tuple[_Serializer[Any], Validator]":
+        if not isinstance(self._type_info, ArrayType):
+            raise TypeError(f"{method} is only supported for list types")
+        if self._item_loader is None:
+            item_type = self._type_info.item_type
+            self._item_loader = (make_encoder(item_type), self._new_validator(item_type))
+        return self._item_loader
+
+
+class _ErrorMessagesValidator(Validator):
+    """
+    Replaces the messages of the validator errors with the ErrorMessage of the field they belong to,
+    or with the error_messages template of the failed schema keyword.
+    """
+
+    def __init__(self, validator: Validator, type_info: Type, error_messages: Mapping[str, str]) -> None:
+        self._validator = validator
+        self._type_info = type_info
+        self._error_messages = error_messages
+
+    def validate(self, data: Any) -> None:
+        if errors := self.errors(data):
+            raise SchemaValidationError(errors)
+
+    def errors(self, data: Any) -> list[ErrorItem]:
+        return [self._with_message(error) for error in self._validator.errors(data)]
+
+    def _with_message(self, error: ErrorItem) -> ErrorItem:
+        keyword = error.schema_path.rsplit("/", 1)[-1]
+        template = _field_error_message(self._type_info, error.path) or self._error_messages.get(keyword)
+        if template is None:
+            return error
+        return dataclasses.replace(error, message=_format_message(template, error))
+
+
+def _field_error_message(type_info: Type, path: tuple[Union[str, int], ...]) -> Optional[str]:
+    """
+    Returns the ErrorMessage of the innermost field on the instance path.
+    It covers the field value and its items, fields of a nested entity have their own messages.
+    """
+    message = None
+    for part in path:
+        while isinstance(type_info, (OptionalType, RecursionHolder)):
+            type_info = type_info.inner if isinstance(type_info, OptionalType) else type_info.get_type()
+        if isinstance(type_info, EntityType):
+            field = next((f for f in type_info.fields if part in (f.load_key or f.dict_key, *f.aliases)), None)
+            if field is None:
+                return message
+            message = field.error_message
+            type_info = field.type
+        elif isinstance(type_info, (ArrayType, SetType)):
+            type_info = type_info.item_type
+        elif isinstance(type_info, DictionaryType):
+            type_info = type_info.value_type
+        elif isinstance(type_info, TupleType) and isinstance(part, int):
+            type_info = type_info.item_types[0 if type_info.variadic else min(part, len(type_info.item_types) - 1)]
+        else:
+            return message
+    return message
+
+
+def _format_message(template: str, error: ErrorItem) -> str:
+    # an unknown placeholder or a stray brace keeps the original message
+    try:
+        return template.format(
+            message=error.message, instance_path=error.instance_path, value=error.value, expected=error.expected
+        )
+    except (KeyError, IndexError, ValueError):
+        return error.message
+
+
+# Serializers are cached on the class itself, the cycle through the encoder is traversed by the garbage collector.
+# A cache keyed by the class would keep it alive, the cached serializer references it.
+_CACHE_ATTR = "__serpyco_rs_serializers__"
+# builtins and extension types can't hold the cache, they aren't collected anyway
+_types_cache: "weakref.WeakKeyDictionary[type, tuple[int, dict[Any, Serializer[Any]]]]" = weakref.WeakKeyDictionary()
+_cache_generation = 0
+
+
+def get_or_create_serializer(t: type[_T], **options: Any) -> Serializer[_T]:
+    """
+    Returns a Serializer cached per class and options.
+    Typing aliases (list[A], Annotated[...]) aren't classes, a new Serializer is created for them.
+    """
+    key = tuple(sorted((name, _hashable_option(name, value)) for name, value in options.items()))
+    if not isinstance(t, type):
+        return Serializer(t, **options)
+    cache = t.__dict__.get(_CACHE_ATTR) or _types_cache.get(t)
+    if cache is None or cache[0] != _cache_generation:
+        cache = (_cache_generation, {})
+        try:
+            setattr(t, _CACHE_ATTR, cache)
+        except (AttributeError, TypeError):
+            _types_cache[t] = cache
+    serializers: dict[Any, Serializer[Any]] = cache[1]
+    if key not in serializers:
+        serializers[key] = Serializer(t, **options)
+    return serializers[key]
+
+
+def _hashable_option(name: str, value: Any) -> Any:
+    if isinstance(value, Mapping):
+        return tuple(sorted((k, _hashable_option(name, v)) for k, v in value.items()))
+    if isinstance(value, (list, tuple)):
+        return tuple(_hashable_option(name, v) for v in value)
+    if isinstance(value, (set, frozenset)):
+        return frozenset(_hashable_option(name, v) for v in value)
+    try:
+        hash(value)
+    except TypeError:
+        raise TypeError(f"get_or_create_serializer option {name}={value!r} is not hashable") from None
+    return value
+
+
+_CONFIGURABLE_OPTIONS = frozenset(
+    {
+        "camelcase_fields",
+        "datetime_format",
+        "require_aware_datetimes",
+        "assume_timezone",
+        "convert_datetimes_to_utc",
+        "reveal_secrets",
+        "error_messages",
+    }
+)
+_defaults: dict[str, Any] = {}
+
+
+def configure(**defaults: Any) -> None:
+    """
+    Sets process-wide defaults of Serializer options, e.g. configure(camelcase_fields=True).
+    Options passed to Serializer take precedence. Serializers created before aren't changed,
+    the get_or_create_serializer cache is cleared.
+    """
+    global _cache_generation
+    unknown = defaults.keys() - _CONFIGURABLE_OPTIONS
+    if unknown:
+        raise TypeError(f"Unknown Serializer options: {', '.join(sorted(unknown))}")
+    _defaults.update(defaults)
+    _cache_generation += 1
+
+
+def _option(name: str, value: Any, default: Any) -> Any:
+    if value is not _DEFAULT:
+        return value
+    return _defaults.get(name, default)
+
+
+_DB_NATIVE_TYPES = (
+    IntegerType,
+    FloatType,
+    StringType,
+    BooleanType,
+    DecimalType,
+    BytesType,
+    DateTimeType,
+    DateType,
+    TimeType,
+    TimeDurationType,
+)
+
+
+def _without_required(schema: Any) -> Any:
+    if isinstance(schema, dict):
+        return {
+            key: _without_required(value)
+            for key, value in schema.items()
+            if not (key == "required" and isinstance(value, list))
+        }
+    if isinstance(schema, list):
+        return [_without_required(item) for item in schema]
+    return schema
+
+
+def _attributes_to_data(type_info: Type, value: Any) -> Any:
+    if isinstance(type_info, RecursionHolder):
+        type_info = type_info.get_type()
+    if isinstance(type_info, OptionalType):
+        return None if value is None else _attributes_to_data(type_info.inner, value)
+    if isinstance(type_info, EntityType) and not isinstance(value, Mapping):
+        return {
+            field.load_key or field.dict_key: _attributes_to_data(field.type, getattr(value, field.name))
+            for field in type_info.fields
+            if not field.is_property and not field.is_extra_keys and hasattr(value, field.name)
+        }
+    if isinstance(type_info, (ArrayType, SetType)) and isinstance(value, (list, tuple, set, frozenset)):
+        return [_attributes_to_data(type_info.item_type, item) for item in value]
+    if isinstance(type_info, TupleType) and isinstance(value, (list, tuple)):
+        item_types = list(type_info.item_types) * len(value) if type_info.variadic else type_info.item_types
+        return [_attributes_to_data(item_type, item) for item_type, item in zip(item_types, value)]
+    if isinstance(type_info, DictionaryType) and isinstance(value, Mapping):
+        return {key: _attributes_to_data(type_info.value_type, item) for key, item in value.items()}
+    return value
+
+
+def _db_param_adapter(type_info: Type, uuid_as_str: bool) -> Callable[[Any], Any]:
+    if isinstance(type_info, OptionalType):
+        inner = _db_param_adapter(type_info.inner, uuid_as_str)
+        return lambda value: None if value is None else inner(value)
+    if isinstance(type_info, _DB_NATIVE_TYPES) or (isinstance(type_info, UUIDType) and not uuid_as_str):
+        return lambda value: value
+    if isinstance(type_info, EnumType):
+        return lambda value: value.value
+    return make_encoder(type_info).dump
+
+
+def _encoder_error_item(error: ValidationError, prefix: tuple[Union[str, int], ...] = ()) -> ErrorItem:
+    # load sets the path of the failed value, errors raised elsewhere have none
+    path = (*prefix, *getattr(error, "path", ()))
+    return ErrorItem(message=str(error), instance_path="/".join(map(str, path)), schema_path="", path=path)
+
+
+def _with_index(index: int, error: SchemaValidationError) -> list[ErrorItem]:
+    return _with_path((index,), error)
+
+
+def _with_path(path: tuple[Union[str, int], ...], error: SchemaValidationError) -> list[ErrorItem]:
+    return [
+        dataclasses.replace(
+            err,
+            instance_path="/".join(filter(None, ("/".join(map(str, path)), err.instance_path))),
+            path=(*path, *err.path),
+        )
+        for err in error.errors
+    ]
+
+
+_decoder = json.JSONDecoder()
+_whitespace = " \t\n\r"
+
+
+def _iter_json_array(data: Union[bytes, str]) -> Iterator[Any]:
+    doc = data.decode() if isinstance(data, bytes) else data
+    pos = _skip_whitespace(doc, 0)
+    if doc[pos : pos + 1] != "[":
+        raise json.JSONDecodeError("Expecting '['", doc, pos)
+    pos = _skip_whitespace(doc, pos + 1)
+    if doc[pos : pos + 1] != "]":
+        while True:
+            item, pos = _decoder.raw_decode(doc, pos)
+            yield item
+            pos = _skip_whitespace(doc, pos)
+            if doc[pos : pos + 1] == "]":
+                break
+            if doc[pos : pos + 1] != ",":
+                raise json.JSONDecodeError("Expecting ',' delimiter", doc, pos)
+            pos = _skip_whitespace(doc, pos + 1)
+    pos = _skip_whitespace(doc, pos + 1)
+    if pos != len(doc):
+        raise json.JSONDecodeError("Extra data", doc, pos)
+
+
+def _skip_whitespace(doc: str, pos: int) -> int:
+    while pos < len(doc) and doc[pos] in _whitespace:
+        pos += 1
+    return pos
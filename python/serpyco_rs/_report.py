@@ -0,0 +1,89 @@
+import json
+from collections.abc import Mapping, Sequence
+from dataclasses import dataclass, field
+from functools import singledispatch
+from typing import Any
+
+from . import _describe as describe
+
+
+@dataclass
+class LoadReport:
+    ignored_keys: list[str] = field(default_factory=list)
+    defaulted_fields: list[str] = field(default_factory=list)
+
+
+def collect_report(t: describe.Type, data: Any) -> LoadReport:
+    report = LoadReport()
+    _collect(t, data, "", report)
+    return report
+
+
+@singledispatch
+def _collect(_: Any, data: Any, path: str, report: LoadReport) -> None:
+    pass
+
+
+@_collect.register
+def _(arg: describe.OptionalType, data: Any, path: str, report: LoadReport) -> None:
+    if data is not None:
+        _collect(arg.inner, data, path, report)
+
+
+@_collect.register
+def _(arg: describe.JsonStringType, data: Any, path: str, report: LoadReport) -> None:
+    if isinstance(data, str):
+        _collect(arg.inner, json.loads(data), path, report)
+
+
+@_collect.register
+def _(arg: describe.ArrayType, data: Any, path: str, report: LoadReport) -> None:
+    if isinstance(data, Sequence):
+        for index, item in enumerate(data):
+            _collect(arg.item_type, item, _join(path, index), report)
+
+
+@_collect.register
+def _(arg: describe.SetType, data: Any, path: str, report: LoadReport) -> None:
+    if isinstance(data, Sequence):
+        for index, item in enumerate(data):
+            _collect(arg.item_type, item, _join(path, index), report)
+
+
+@_collect.register
+def _(arg: describe.DictionaryType, data: Any, path: str, report: LoadReport) -> None:
+    if isinstance(data, Mapping):
+        for key, value in data.items():
+            _collect(arg.value_type, value, _join(path, key), report)
+
+
+@_collect.register
+def _(arg: describe.TupleType, data: Any, path: str, report: LoadReport) -> None:
+    if isinstance(data, Sequence):
+        for index, (item_type, item) in enumerate(zip(arg.item_types, data)):
+            _collect(item_type, item, _join(path, index), report)
+
+
+@_collect.register
+def _(arg: describe.EntityType, data: Any, path: str, report: LoadReport) -> None:
+    if not isinstance(data, Mapping):
+        return
+    fields = [f for f in arg.fields if not f.is_property]
+    known_keys = {key for f in fields for key in (f.load_key or f.dict_key, *f.aliases)}
+    report.ignored_keys.extend(_join(path, key) for key in data if key not in known_keys)
+    for f in fields:
+        load_key = f.load_key or f.dict_key
+        key = next((key for key in (load_key, *f.aliases) if key in data), None)
+        if key is not None:
+            _collect(f.type, data[key], _join(path, key), report)
+        elif f.default is not describe.NOT_SET or f.default_factory is not describe.NOT_SET:
+            report.defaulted_fields.append(_join(path, load_key))
+
+
+@_collect.register
+def _(arg: describe.RecursionHolder, data: Any, path: str, report: LoadReport) -> None:
+    _collect(arg.get_type(), data, path, report)
+
+
+def _join(path: str, key: Any) -> str:
+    return f"{path}/{key}" if path else str(key)
@@ -1,8 +1,25 @@
-from dataclasses import dataclass
+from dataclasses import dataclass, field
+from typing import Any, Optional, Union
 
-from ._impl import ValidationError
+from ._impl import (
+    ConstraintError,
+    LoadCancelled,
+    LoadTimeout,
+    RequiredFieldError,
+    TypeMismatchError,
+    ValidationError,
+)
 
-__all__ = ["ValidationError", "SchemaValidationError", "ErrorItem"]
+__all__ = [
+    "ValidationError",
+    "RequiredFieldError",
+    "TypeMismatchError",
+    "ConstraintError",
+    "SchemaValidationError",
+    "ErrorItem",
+    "LoadCancelled",
+    "LoadTimeout",
+]
 
 
 @dataclass
@@ -10,8 +27,26 @@ class ErrorItem:
     message: str
     instance_path: str
     schema_path: str
+    # instance_path segments, ints are list indexes and strs are dict keys
+    path: tuple[Union[str, int], ...] = field(default=(), compare=False)
+    # repr of the offending value, truncated, None for objects and arrays
+    value: Optional[str] = field(default=None, compare=False)
+    # JSON schema type expected at instance_path, e.g. "integer", a list of types for unions
+    expected: Union[str, list[str], None] = field(default=None, compare=False)
 
 
 class SchemaValidationError(ValidationError):
     def __init__(self, errors: list[ErrorItem]) -> None:
         self.errors = errors
+
+    def __reduce__(self) -> tuple[Any, ...]:
+        return self.__class__, (self.errors,), self.__dict__
+
+    def by_path(self) -> dict[str, list[ErrorItem]]:
+        """
+        Groups the errors by instance_path, in the order of their first error.
+        """
+        grouped: dict[str, list[ErrorItem]] = {}
+        for error in self.errors:
+            grouped.setdefault(error.instance_path, []).append(error)
+        return grouped
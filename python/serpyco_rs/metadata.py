@@ -1,16 +1,32 @@
 from dataclasses import dataclass
+from datetime import date, time, tzinfo
 from decimal import Decimal
 from enum import Enum
-from typing import Union
+from typing import Any, Callable, Union
 
 
 @dataclass(frozen=True)
 class Min:
-    value: Union[int, float, Decimal]
+    value: Union[int, float, Decimal, date, time, str]
 
 
 @dataclass(frozen=True)
 class Max:
+    value: Union[int, float, Decimal, date, time, str]
+
+
+@dataclass(frozen=True)
+class ExclusiveMin:
+    value: Union[int, float, Decimal]
+
+
+@dataclass(frozen=True)
+class ExclusiveMax:
+    value: Union[int, float, Decimal]
+
+
+@dataclass(frozen=True)
+class MultipleOf:
     value: Union[int, float, Decimal]
 
 
@@ -29,6 +45,255 @@ class Places:
     value: int
 
 
+@dataclass(frozen=True)
+class MaxDigits:
+    value: int
+
+
+@dataclass(frozen=True)
+class LenientDate:
+    pass
+
+
+@dataclass(frozen=True)
+class ClampYear:
+    """Loads dates and datetimes before year 1 or after year 9999 as the nearest supported value."""
+
+
+@dataclass(frozen=True)
+class LenientUUID:
+    """Also accepts 16 big-endian bytes and 128-bit ints on load."""
+
+
+@dataclass(frozen=True)
+class EnumByName:
+    """
+    Loads enum members by name ("RED") instead of value, with values=True both are accepted.
+    Members are dumped by value, see EnumDumpFormat.
+    """
+
+    values: bool = False
+
+
+@dataclass(frozen=True)
+class EnumDumpFormat:
+    """
+    Enum dump format, one of "str" (str of the value, the default), "value" (e.g. the int of IntEnum) or "name".
+    Flag enums are dumped as the int bitmask ("value", the default) or as the list of member names ("name"),
+    both forms are accepted on load.
+    """
+
+    format: str
+
+
+@dataclass(frozen=True)
+class PrefixedInt:
+    """
+    Integer field also accepts strings on load: decimal, "0x1F", "0o17" or "0b101".
+    """
+
+
+@dataclass(frozen=True)
+class RedactSecret:
+    """Dumps Secret fields as "***" instead of the value."""
+
+
+@dataclass(frozen=True)
+class StripWhitespace:
+    """Strips leading and trailing whitespace on load, before MinLength/MaxLength are checked."""
+
+
+@dataclass(frozen=True)
+class ToLower:
+    """Lowercases str fields on load."""
+
+
+@dataclass(frozen=True)
+class ToUpper:
+    """Uppercases str fields on load."""
+
+
+@dataclass(frozen=True)
+class StringFormat:
+    """
+    Validates and normalizes a str field on load.
+    "phone": international phone number, normalized to E.164 ("+14155550123").
+    "iso3166-alpha2": country code ("DE"), "bcp47": language tag ("en-US"), "iso4217": currency code ("EUR").
+    "semver": semantic version ("1.2.3-rc.1"), Min/Max bounds are compared by version precedence.
+    "jwt": three dot-separated base64url segments, only for Secret fields.
+    """
+
+    format: str
+
+
+@dataclass(frozen=True)
+class CanonicalUUID:
+    """
+    Loads a str field as a UUID in lowercase canonical form.
+    Braces, "urn:uuid:" prefix, uppercase and missing hyphens are accepted on load.
+    """
+
+
+@dataclass(frozen=True)
+class AbsolutePath:
+    pass
+
+
+@dataclass(frozen=True)
+class NoParentRefs:
+    """Disallows ".." path components."""
+
+
+@dataclass(frozen=True)
+class UniqueItems:
+    pass
+
+
+@dataclass(frozen=True)
+class SortedBy:
+    """Requires the loaded items to be ordered by the given field, order is "asc" or "desc"."""
+
+    field: str
+    order: str = "asc"
+
+
+@dataclass(frozen=True)
+class UniqueBy:
+    """Requires the given field to be unique across the loaded items."""
+
+    field: str
+
+
+@dataclass(frozen=True)
+class ZSuffix:
+    """Dumps UTC datetimes with "Z" instead of "+00:00"."""
+
+
+@dataclass(frozen=True)
+class RequireAware:
+    """
+    Naive datetimes are rejected on load.
+    """
+
+
+@dataclass(frozen=True)
+class AssumeTimezone:
+    """
+    Naive datetimes get this timezone on load.
+    """
+
+    tz: tzinfo
+
+
+@dataclass(frozen=True)
+class ConvertToUTC:
+    """
+    Aware datetimes are converted to UTC on load.
+    """
+
+
+@dataclass(frozen=True)
+class JsonString:
+    """
+    Field value is a JSON document encoded as a string.
+    It is parsed before load and stringified after dump.
+    """
+
+
+@dataclass(frozen=True)
+class DateTimeFormats:
+    """
+    Accepted datetime formats, tried in order on load. Dump uses the first one.
+    Each format is "iso", "iso_seconds", "epoch" (unix timestamp in seconds), "epoch_ms" (in milliseconds),
+    "rfc2822" ("Tue, 15 Nov 1994 08:12:31 GMT", also HTTP dates) or a strftime pattern.
+    """
+
+    formats: tuple[str, ...]
+
+
+@dataclass(frozen=True)
+class DateTimeDumpFormat:
+    """
+    Datetime dump format, overrides the first of DateTimeFormats.
+    One of "iso", "iso_seconds" (without microseconds), "epoch", "epoch_ms" or a strftime pattern.
+    """
+
+    format: str
+
+
+@dataclass(frozen=True)
+class Examples:
+    """
+    Example values of the type, dumped by its encoder into "examples" of the JSON schema.
+    """
+
+    values: tuple[Any, ...]
+
+
+@dataclass(frozen=True)
+class ErrorMessage:
+    """
+    Replaces validation messages of the field value, e.g. ErrorMessage("Enter your age in years").
+    Applies to schema errors and to ValidationError raised on load, like an invalid money amount.
+    {message} and {instance_path} in the template are the original message and the path of the value.
+    Errors of nested entity fields keep their own messages.
+    """
+
+    template: str
+
+
+@dataclass(frozen=True)
+class Aliases:
+    """
+    Old dict keys of the field, tried in order on load when the dict key is missing.
+    Dump always uses the dict key.
+    """
+
+    keys: tuple[str, ...]
+
+
+@dataclass(frozen=True)
+class Computed:
+    """
+    Marks the return type of a property, e.g. `def full_name(self) -> Annotated[str, Computed()]`.
+    The property value is dumped with the fields and ignored on load.
+    """
+
+
+@dataclass(frozen=True)
+class ExtraKeys:
+    """
+    Marks a dict[str, Any] field that collects the input keys not consumed by the other fields on load,
+    its items are dumped back as keys of the entity.
+    """
+
+
+@dataclass(frozen=True)
+class LoadKey:
+    """Dict key of the field on load, the dict key is still used on dump."""
+
+    key: str
+
+
+@dataclass(frozen=True)
+class DumpKey:
+    """Dict key of the field on dump, the dict key is still used on load."""
+
+    key: str
+
+
+@dataclass(frozen=True)
+class SkipIfDefault:
+    """Leaves the field out of dump output when it's equal to its default."""
+
+
+@dataclass(frozen=True)
+class SkipIf:
+    """Leaves the field out of dump output when predicate(value) is true."""
+
+    predicate: Callable[[Any], bool]
+
+
 class Format(Enum):
     no_format = "no_format"
     camel_case = "camel_case"
@@ -41,3 +306,68 @@ class FiledFormat:
 
 CamelCase: FiledFormat = FiledFormat(Format.camel_case)
 NoFormat: FiledFormat = FiledFormat(Format.no_format)
+
+
+class BytesEncoding(Enum):
+    raw = "raw"
+    base64 = "base64"
+    base64_nopad = "base64_nopad"
+    base64url = "base64url"
+    base64url_nopad = "base64url_nopad"
+    base32 = "base32"
+    base32_nopad = "base32_nopad"
+    base58 = "base58"
+    hex = "hex"
+
+
+@dataclass(frozen=True)
+class BytesFormat:
+    encoding: BytesEncoding
+
+
+Base64: BytesFormat = BytesFormat(BytesEncoding.base64)
+Base64Url: BytesFormat = BytesFormat(BytesEncoding.base64url)
+Base32: BytesFormat = BytesFormat(BytesEncoding.base32)
+Base58: BytesFormat = BytesFormat(BytesEncoding.base58)
+Hex: BytesFormat = BytesFormat(BytesEncoding.hex)
+
+
+class DurationFormat(Enum):
+    iso8601 = "iso8601"
+    seconds = "seconds"
+
+
+@dataclass(frozen=True)
+class TimeDeltaFormat:
+    format: DurationFormat
+
+
+IsoDuration: TimeDeltaFormat = TimeDeltaFormat(DurationFormat.iso8601)
+SecondsDuration: TimeDeltaFormat = TimeDeltaFormat(DurationFormat.seconds)
+
+
+class UUIDFormat(Enum):
+    canonical = "canonical"  # 2d3e3bd3-9b7d-4c1f-8fbb-98a9e3d7b1a4
+    hex = "hex"  # 2d3e3bd39b7d4c1f8fbb98a9e3d7b1a4
+    urn = "urn"  # urn:uuid:2d3e3bd3-9b7d-4c1f-8fbb-98a9e3d7b1a4
+    uppercase = "uppercase"  # 2D3E3BD3-9B7D-4C1F-8FBB-98A9E3D7B1A4
+
+
+@dataclass(frozen=True)
+class UUIDDumpFormat:
+    format: UUIDFormat
+
+
+class TimePrecision(Enum):
+    seconds = "seconds"
+    milliseconds = "milliseconds"
+
+
+@dataclass(frozen=True)
+class DumpPrecision:
+    """
+    Sub-second precision of dumped datetimes and times, truncated unless round is set.
+    """
+
+    precision: TimePrecision
+    round: bool = False
@@ -0,0 +1,13 @@
+import dataclasses
+from decimal import Decimal
+
+
+@dataclasses.dataclass(frozen=True)
+class Money:
+    """Decimal amount with an ISO 4217 currency code.
+
+    Dumped as {"amount": "12.34", "currency": "EUR"}, loaded from the same object or from "12.34 EUR".
+    """
+
+    amount: Decimal
+    currency: str
@@ -0,0 +1,64 @@
+from typing import Any
+
+
+class Secret:
+    """Opaque secret string.
+
+    repr() and str() show "***" so the value doesn't leak into logs and tracebacks.
+    """
+
+    __slots__ = ("_value",)
+
+    def __init__(self, value: str) -> None:
+        self._value = value
+
+    def get_secret_value(self) -> str:
+        return self._value
+
+    def __repr__(self) -> str:
+        return "Secret('***')"
+
+    def __str__(self) -> str:
+        return "***"
+
+    def __eq__(self, other: Any) -> bool:
+        return isinstance(other, Secret) and other._value == self._value
+
+    def __hash__(self) -> int:
+        return hash(self._value)
+
+
+class SecretStr(Secret):
+    """Secret string that is dumped as "**********" unless the Serializer has reveal_secrets=True."""
+
+    __slots__ = ()
+
+    def __repr__(self) -> str:
+        return "SecretStr('**********')"
+
+    def __str__(self) -> str:
+        return "**********"
+
+
+class SecretBytes:
+    """Same as SecretStr for bytes, revealed values are dumped as base64 and strings are loaded as base64."""
+
+    __slots__ = ("_value",)
+
+    def __init__(self, value: bytes) -> None:
+        self._value = value
+
+    def get_secret_value(self) -> bytes:
+        return self._value
+
+    def __repr__(self) -> str:
+        return "SecretBytes(b'**********')"
+
+    def __str__(self) -> str:
+        return "**********"
+
+    def __eq__(self, other: Any) -> bool:
+        return isinstance(other, SecretBytes) and other._value == self._value
+
+    def __hash__(self) -> int:
+        return hash(self._value)
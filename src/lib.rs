@@ -5,13 +5,36 @@ use pyo3::prelude::*;
 /// A Python module implemented in Rust.
 #[pymodule]
 fn _serpyco_rs(py: Python, m: &PyModule) -> PyResult<()> {
-    serializer::init(py);
+    serializer::init(py)?;
     m.add_class::<serializer::Serializer>()?;
+    m.add_class::<serializer::CancellationToken>()?;
+    m.add_class::<serializer::Unset>()?;
     m.add_function(wrap_pyfunction!(serializer::make_encoder, m)?)?;
+    m.add_function(wrap_pyfunction!(serializer::py_parse_duration, m)?)?;
+    m.add_function(wrap_pyfunction!(serializer::py_dump_duration, m)?)?;
+    m.add_function(wrap_pyfunction!(serializer::self_check, m)?)?;
+    m.add_function(wrap_pyfunction!(serializer::presize_stats, m)?)?;
     m.add(
         "ValidationError",
         py.get_type::<serializer::ValidationError>(),
     )?;
+    m.add(
+        "RequiredFieldError",
+        py.get_type::<serializer::RequiredFieldError>(),
+    )?;
+    m.add(
+        "TypeMismatchError",
+        py.get_type::<serializer::TypeMismatchError>(),
+    )?;
+    m.add(
+        "ConstraintError",
+        py.get_type::<serializer::ConstraintError>(),
+    )?;
+    m.add("LoadCancelled", py.get_type::<serializer::LoadCancelled>())?;
+    m.add("LoadTimeout", py.get_type::<serializer::LoadTimeout>())?;
+    m.add("UNSET", unsafe {
+        py.from_borrowed_ptr::<PyAny>(serializer::UNSET)
+    })?;
 
     Ok(())
 }
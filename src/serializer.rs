@@ -1,11 +1,32 @@
+mod bytesutil;
+mod cancel;
+mod currency;
 mod dateutil;
+mod dumpfilter;
+mod dumppath;
 mod encoders;
+mod iputil;
+mod localeutil;
 mod macros;
 mod main;
+mod options;
+mod panics;
+#[cfg(feature = "phone")]
+mod phoneutil;
+mod presize;
 mod py;
+mod scratch;
+mod selfcheck;
+mod semverutil;
+mod textutil;
 mod types;
+mod unset;
 
+pub use cancel::{CancellationToken, LoadCancelled, LoadTimeout};
 pub use encoders::Serializer;
-pub use encoders::ValidationError;
-pub use main::make_encoder;
+pub use encoders::{ConstraintError, RequiredFieldError, TypeMismatchError, ValidationError};
+pub use main::{make_encoder, py_dump_duration, py_parse_duration};
+pub use presize::presize_stats;
+pub use selfcheck::self_check;
 pub use types::init;
+pub use unset::{Unset, UNSET};
@@ -0,0 +1,285 @@
+use pyo3::PyErr;
+
+use super::encoders::ValidationError;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+#[derive(Debug, Clone, Copy)]
+pub enum BytesEncoding {
+    Base64 { url_safe: bool, padded: bool },
+    Base32 { padded: bool },
+    Base58,
+    Hex,
+}
+
+impl BytesEncoding {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "base64" => Some(Self::Base64 {
+                url_safe: false,
+                padded: true,
+            }),
+            "base64_nopad" => Some(Self::Base64 {
+                url_safe: false,
+                padded: false,
+            }),
+            "base64url" => Some(Self::Base64 {
+                url_safe: true,
+                padded: true,
+            }),
+            "base64url_nopad" => Some(Self::Base64 {
+                url_safe: true,
+                padded: false,
+            }),
+            "base32" => Some(Self::Base32 { padded: true }),
+            "base32_nopad" => Some(Self::Base32 { padded: false }),
+            "base58" => Some(Self::Base58),
+            "hex" => Some(Self::Hex),
+            _ => None,
+        }
+    }
+
+    /// Appends the encoded data to `out`.
+    pub fn encode(&self, data: &[u8], out: &mut String) {
+        match *self {
+            Self::Base64 { padded, .. } => bits_encode(data, self.alphabet(), 6, padded, out),
+            Self::Base32 { padded } => bits_encode(data, self.alphabet(), 5, padded, out),
+            Self::Base58 => base58_encode(data, out),
+            Self::Hex => hex_encode(data, out),
+        }
+    }
+
+    pub fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        match *self {
+            Self::Base64 { padded, .. } => bits_decode(data, *self, 6, padded),
+            Self::Base32 { padded } => bits_decode(data, *self, 5, padded),
+            Self::Base58 => base58_decode(data),
+            Self::Hex => hex_decode(data),
+        }
+    }
+
+    fn alphabet(&self) -> &'static [u8] {
+        match self {
+            Self::Base64 {
+                url_safe: false, ..
+            } => BASE64_ALPHABET,
+            Self::Base64 { url_safe: true, .. } => BASE64_URL_ALPHABET,
+            Self::Base32 { .. } => BASE32_ALPHABET,
+            Self::Base58 => BASE58_ALPHABET,
+            Self::Hex => HEX_ALPHABET,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Base64 {
+                url_safe: false,
+                padded: true,
+            } => "base64",
+            Self::Base64 {
+                url_safe: false,
+                padded: false,
+            } => "base64_nopad",
+            Self::Base64 {
+                url_safe: true,
+                padded: true,
+            } => "base64url",
+            Self::Base64 {
+                url_safe: true,
+                padded: false,
+            } => "base64url_nopad",
+            Self::Base32 { padded: true } => "base32",
+            Self::Base32 { padded: false } => "base32_nopad",
+            Self::Base58 => "base58",
+            Self::Hex => "hex",
+        }
+    }
+}
+
+pub struct DecodeError {
+    encoding: BytesEncoding,
+    message: &'static str,
+}
+
+impl From<DecodeError> for PyErr {
+    fn from(e: DecodeError) -> Self {
+        ValidationError::new_err(format!(
+            "Fail decode {} bytes: {}",
+            e.encoding.name(),
+            e.message
+        ))
+    }
+}
+
+/// Encodes `bits` per character, used by base64 (6) and base32 (5).
+fn bits_encode(data: &[u8], alphabet: &[u8], bits: u32, padded: bool, out: &mut String) {
+    let mask = (1 << bits) - 1;
+    let start = out.len();
+    out.reserve(data.len() * 8 / bits as usize + 8);
+    let mut buffer: u32 = 0;
+    let mut buffered = 0;
+    for &b in data {
+        buffer = buffer << 8 | b as u32;
+        buffered += 8;
+        while buffered >= bits {
+            buffered -= bits;
+            out.push(alphabet[((buffer >> buffered) & mask) as usize] as char);
+        }
+    }
+    if buffered > 0 {
+        out.push(alphabet[((buffer << (bits - buffered)) & mask) as usize] as char);
+    }
+    if padded {
+        let group = group_len(bits);
+        while !(out.len() - start).is_multiple_of(group) {
+            out.push('=');
+        }
+    }
+}
+
+fn bits_decode(
+    data: &str,
+    encoding: BytesEncoding,
+    bits: u32,
+    padded: bool,
+) -> Result<Vec<u8>, DecodeError> {
+    let error = |message| DecodeError { encoding, message };
+    let mut data = data.as_bytes();
+    if padded {
+        let group = group_len(bits);
+        if !data.len().is_multiple_of(group) {
+            return Err(error("invalid length"));
+        }
+        let padding = data.iter().rev().take_while(|&&c| c == b'=').count();
+        data = &data[..data.len() - padding];
+        if data.contains(&b'=') || padding != (group - data.len() % group) % group {
+            return Err(error("invalid padding"));
+        }
+    }
+    // a trailing character must carry at least one full byte
+    if data.len() * bits as usize % 8 >= bits as usize {
+        return Err(error(match padded {
+            true => "invalid padding",
+            false => "invalid length",
+        }));
+    }
+
+    let alphabet = encoding.alphabet();
+    let mut result = Vec::with_capacity(data.len() * bits as usize / 8);
+    let mut buffer: u32 = 0;
+    let mut buffered = 0;
+    for &c in data {
+        let value = alphabet
+            .iter()
+            .position(|&a| a == c)
+            .ok_or_else(|| error("invalid character"))?;
+        buffer = buffer << bits | value as u32;
+        buffered += bits;
+        if buffered >= 8 {
+            buffered -= 8;
+            result.push((buffer >> buffered) as u8);
+        }
+    }
+    // the bits left over from the last character are padding, only one encoding per value
+    if buffer & ((1 << buffered) - 1) != 0 {
+        return Err(error("non-zero trailing bits"));
+    }
+    Ok(result)
+}
+
+fn group_len(bits: u32) -> usize {
+    match bits {
+        6 => 4,
+        _ => 8,
+    }
+}
+
+fn base58_encode(data: &[u8], out: &mut String) {
+    let zeros = data.iter().take_while(|&&b| b == 0).count();
+    // base58 digits, least significant first
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 138 / 100 + 1);
+    for &b in &data[zeros..] {
+        let mut carry = b as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    out.reserve(zeros + digits.len());
+    out.extend(std::iter::repeat_n('1', zeros));
+    out.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&d| BASE58_ALPHABET[d as usize] as char),
+    );
+}
+
+fn base58_decode(data: &str) -> Result<Vec<u8>, DecodeError> {
+    let data = data.as_bytes();
+    let zeros = data.iter().take_while(|&&c| c == b'1').count();
+    // bytes, least significant first
+    let mut bytes: Vec<u8> = Vec::with_capacity(data.len());
+    for &c in &data[zeros..] {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or(DecodeError {
+                encoding: BytesEncoding::Base58,
+                message: "invalid character",
+            })? as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let mut result = vec![0; zeros];
+    result.extend(bytes.iter().rev());
+    Ok(result)
+}
+
+fn hex_encode(data: &[u8], out: &mut String) {
+    out.reserve(data.len() * 2);
+    for &b in data {
+        out.push(HEX_ALPHABET[(b >> 4) as usize] as char);
+        out.push(HEX_ALPHABET[(b & 0x0f) as usize] as char);
+    }
+}
+
+fn hex_decode(data: &str) -> Result<Vec<u8>, DecodeError> {
+    let error = |message| DecodeError {
+        encoding: BytesEncoding::Hex,
+        message,
+    };
+    let data = data.as_bytes();
+    if !data.len().is_multiple_of(2) {
+        return Err(error("invalid length"));
+    }
+
+    data.chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16);
+            let low = (pair[1] as char).to_digit(16);
+            match (high, low) {
+                (Some(high), Some(low)) => Ok((high << 4 | low) as u8),
+                _ => Err(error("invalid character")),
+            }
+        })
+        .collect()
+}
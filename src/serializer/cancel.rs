@@ -0,0 +1,141 @@
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use pyo3::exceptions::PyException;
+use pyo3::{pyclass, pymethods, PyErr, PyResult, Python};
+
+use super::macros::ffi;
+use super::py::error_on_minusone;
+
+pyo3::create_exception!(serpyco_rs, LoadCancelled, PyException);
+pyo3::create_exception!(serpyco_rs, LoadTimeout, LoadCancelled);
+
+/// Items loaded between signal and deadline checks, the token is checked for each item.
+const CHECK_INTERVAL: u32 = 16;
+
+/// Cancellation token and time budget of a single load.
+#[derive(Debug)]
+pub struct Limits {
+    cancelled: Option<Arc<AtomicBool>>,
+    deadline: Option<Instant>,
+    max_duration_ms: u64,
+    items_since_check: Cell<u32>,
+    // instance path of a timed out load, innermost key first
+    timeout_path: RefCell<Option<Vec<String>>>,
+}
+
+#[pyclass]
+#[derive(Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+#[pymethods]
+impl CancellationToken {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Safe to call from signal handlers, they are run during the load.
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    #[getter]
+    fn cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+impl Limits {
+    /// None if there is nothing to check, an already cancelled token fails before any item is loaded.
+    pub fn new(
+        token: Option<&CancellationToken>,
+        max_duration_ms: Option<u64>,
+    ) -> PyResult<Option<Self>> {
+        if token.is_none() && max_duration_ms.is_none() {
+            return Ok(None);
+        }
+        if token.is_some_and(|token| token.cancelled()) {
+            return Err(LoadCancelled::new_err("Load was cancelled"));
+        }
+        Ok(Some(Self {
+            cancelled: token.map(|token| token.cancelled.clone()),
+            deadline: max_duration_ms.map(|ms| Instant::now() + Duration::from_millis(ms)),
+            max_duration_ms: max_duration_ms.unwrap_or_default(),
+            items_since_check: Cell::new(0),
+            timeout_path: RefCell::new(None),
+        }))
+    }
+
+    /// Called for each loaded item, checks the token and every `CHECK_INTERVAL` items the rest of the limits.
+    #[inline]
+    pub fn check(&self) -> PyResult<()> {
+        self.check_cancelled()?;
+        let count = self.items_since_check.get() + 1;
+        if count < CHECK_INTERVAL {
+            self.items_since_check.set(count);
+            return Ok(());
+        }
+        self.check_now()
+    }
+
+    /// Checks all the limits, called for each entity.
+    pub fn check_now(&self) -> PyResult<()> {
+        self.items_since_check.set(0);
+        // Python signal handlers only run when asked to
+        error_on_minusone(ffi!(PyErr_CheckSignals()))?;
+        self.check_cancelled()?;
+        if let Some(deadline) = self.deadline {
+            if Instant::now() > deadline {
+                self.timeout_path.replace(Some(vec![]));
+                return Err(LoadTimeout::new_err(()));
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn check_cancelled(&self) -> PyResult<()> {
+        match &self.cancelled {
+            Some(cancelled) if cancelled.load(Ordering::Relaxed) => {
+                Err(LoadCancelled::new_err("Load was cancelled"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Records the key of the value that was loading when the time budget ran out.
+    #[inline]
+    pub fn with_timeout_path(&self, e: PyErr, key: impl FnOnce() -> String) -> PyErr {
+        if let Some(path) = self.timeout_path.borrow_mut().as_mut() {
+            path.push(key());
+        }
+        e
+    }
+
+    /// Replaces the error of a timed out load with the one that has the message and the path.
+    pub fn timeout_error(&self, e: PyErr) -> PyErr {
+        match self.timeout_path.take() {
+            Some(mut path) => {
+                path.reverse();
+                let path = path.join("/");
+                let message = format!(
+                    "Load took longer than {} ms, stopped at {:?}",
+                    self.max_duration_ms, path
+                );
+                Python::with_gil(|py| {
+                    let error = LoadTimeout::new_err(message);
+                    match error.value(py).setattr("path", path) {
+                        Ok(()) => error,
+                        Err(e) => e,
+                    }
+                })
+            }
+            None => e,
+        }
+    }
+}
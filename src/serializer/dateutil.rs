@@ -1,15 +1,23 @@
+use std::fmt::Write;
 use std::os::raw::c_int;
 
 use chrono::{
     DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, ParseError,
     Timelike, Utc,
 };
-use pyo3::{PyErr, PyResult};
-use pyo3_ffi::{PyObject, PyTimeZone_FromOffset};
+use pyo3::exceptions::PyOverflowError;
+use pyo3::types::IntoPyDict;
+use pyo3::{IntoPyPointer, PyAny, PyErr, PyResult, Python};
+use pyo3_ffi::{
+    PyDateTime_Check, PyDateTime_DATE_GET_HOUR, PyDateTime_DATE_GET_MICROSECOND,
+    PyDateTime_DATE_GET_MINUTE, PyDateTime_DATE_GET_SECOND, PyDateTime_DELTA_GET_DAYS,
+    PyDateTime_DELTA_GET_MICROSECONDS, PyDateTime_DELTA_GET_SECONDS, PyDateTime_GET_DAY,
+    PyDateTime_GET_MONTH, PyDateTime_GET_YEAR, PyDelta_Check, PyObject, PyTimeZone_FromOffset,
+};
 
 use crate::serializer::types::NONE_PY_TYPE;
 
-use super::encoders::ValidationError;
+use super::encoders::{TypeMismatchError, ValidationError};
 use super::py::from_ptr_or_err;
 
 pub fn parse_time(value: &str) -> PyResult<*mut PyObject> {
@@ -43,50 +51,263 @@ pub fn parse_time(value: &str) -> PyResult<*mut PyObject> {
     }
 }
 
-pub fn parse_date(value: &str) -> PyResult<*mut PyObject> {
-    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(InnerParseError::from)?;
+pub fn parse_date(value: &str, lenient: bool, clamp_year: bool) -> PyResult<*mut PyObject> {
+    let mut date = NaiveDate::parse_from_str(value, "%Y-%m-%d");
+    if lenient {
+        // ISO 8601 week date (2024-W05-2) and ordinal date (2024-033)
+        date = date
+            .or_else(|_| NaiveDate::parse_from_str(value, "%G-W%V-%u"))
+            .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%j"));
+    }
+    let date = date.map_err(InnerParseError::from)?;
+    let (year, month, day) = match check_year_range(date.year(), clamp_year)? {
+        Some(MIN_YEAR) => (MIN_YEAR, 1, 1),
+        Some(_) => (MAX_YEAR, 12, 31),
+        None => (date.year(), date.month(), date.day()),
+    };
     let api = ensure_datetime_api();
     unsafe {
         let ptr = (api.Date_FromDate)(
-            date.year(),
-            c_int::from(date.month() as u8),
-            c_int::from(date.day() as u8),
+            year,
+            c_int::from(month as u8),
+            c_int::from(day as u8),
             api.DateType,
         );
         from_ptr_or_err(ptr)
     }
 }
 
-pub fn parse_datetime(value: &str) -> PyResult<*mut PyObject> {
+pub fn parse_datetime(value: &str, clamp_year: bool) -> PyResult<*mut PyObject> {
     match DateTime::parse_from_rfc3339(value) {
         Ok(datetime) => {
             let tz = datetime.offset().fix();
             let py_tz = py_timezone_from_fixed_offset(tz)?;
-            make_py_datetime(datetime, datetime, Some(py_tz))
+            make_py_datetime(datetime, datetime, Some(py_tz), clamp_year)
         }
         Err(_) => {
             let datetime = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
                 .map_err(InnerParseError::from)?;
-            make_py_datetime(datetime, datetime, None)
+            make_py_datetime(datetime, datetime, None, clamp_year)
         }
     }
 }
 
+#[derive(Debug, Clone)]
+pub enum DateTimeFormat {
+    Iso,
+    IsoSeconds,
+    Epoch,
+    EpochMillis,
+    Rfc2822,
+    Custom(String),
+}
+
+impl DateTimeFormat {
+    pub fn from_name(name: String) -> Self {
+        match name.as_str() {
+            "iso" => Self::Iso,
+            "iso_seconds" => Self::IsoSeconds,
+            "epoch" => Self::Epoch,
+            "epoch_ms" => Self::EpochMillis,
+            "rfc2822" => Self::Rfc2822,
+            _ => Self::Custom(name),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Iso => "iso",
+            Self::IsoSeconds => "iso_seconds",
+            Self::Epoch => "epoch",
+            Self::EpochMillis => "epoch_ms",
+            Self::Rfc2822 => "rfc2822",
+            Self::Custom(format) => format,
+        }
+    }
+}
+
+/// Sub-second precision of dumped datetimes and times.
+#[derive(Debug, Clone)]
+pub struct SubsecondPrecision {
+    unit: u32,
+    round: bool,
+}
+
+impl SubsecondPrecision {
+    pub fn from_name(name: &str, round: bool) -> Option<Self> {
+        let unit = match name {
+            "seconds" => 1_000_000,
+            "milliseconds" => 1_000,
+            _ => return None,
+        };
+        Some(Self { unit, round })
+    }
+
+    /// Returns a new reference to the datetime or time with adjusted microseconds.
+    /// Values that would round past datetime.max or past midnight of a time are truncated instead.
+    pub fn apply(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        Python::with_gil(|py| {
+            let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+            let micros: u32 = value.getattr("microsecond")?.extract()?;
+            let truncated = micros - micros % self.unit;
+            let round_up = self.round && micros % self.unit >= self.unit / 2;
+            let replace = |micros: u32| {
+                let kwargs = [("microsecond", micros)].into_py_dict(py);
+                value.call_method("replace", (), Some(kwargs))
+            };
+            let result = match round_up {
+                false => replace(truncated)?,
+                true if truncated + self.unit < 1_000_000 => replace(truncated + self.unit)?,
+                // rounds up to the next second
+                true if value.hasattr("date")? => {
+                    let second = py.import("datetime")?.getattr("timedelta")?.call1((0, 1))?;
+                    let truncated = replace(truncated)?;
+                    match truncated.call_method1("__add__", (second,)) {
+                        Ok(result) => result,
+                        Err(e) if e.is_instance_of::<PyOverflowError>(py) => truncated,
+                        Err(e) => return Err(e),
+                    }
+                }
+                // times have no arithmetic and must not wrap to 00:00:00
+                true => {
+                    let (hour, minute, second): (u32, u32, u32) = (
+                        value.getattr("hour")?.extract()?,
+                        value.getattr("minute")?.extract()?,
+                        value.getattr("second")?.extract()?,
+                    );
+                    match hour * 3600 + minute * 60 + second + 1 {
+                        86_400 => replace(truncated)?,
+                        next => {
+                            let kwargs = [
+                                ("hour", next / 3600),
+                                ("minute", next / 60 % 60),
+                                ("second", next % 60),
+                                ("microsecond", 0),
+                            ];
+                            value.call_method("replace", (), Some(kwargs.into_py_dict(py)))?
+                        }
+                    }
+                }
+            };
+            Ok(result.into_ptr())
+        })
+    }
+
+    /// isoformat of the datetime or time with only the digits of the precision.
+    pub fn isoformat(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let timespec = match self.unit {
+            1_000_000 => "seconds",
+            _ => "milliseconds",
+        };
+        Python::with_gil(|py| {
+            let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+            let kwargs = [("timespec", timespec)].into_py_dict(py);
+            Ok(value.call_method("isoformat", (), Some(kwargs))?.into_ptr())
+        })
+    }
+}
+
+pub fn parse_datetime_with_format(
+    value: &str,
+    format: &str,
+    clamp_year: bool,
+) -> PyResult<*mut PyObject> {
+    if let Ok(datetime) = DateTime::parse_from_str(value, format) {
+        let py_tz = py_timezone_from_fixed_offset(datetime.offset().fix())?;
+        return make_py_datetime(datetime, datetime, Some(py_tz), clamp_year);
+    }
+    let datetime = NaiveDateTime::parse_from_str(value, format).or_else(|e| {
+        // date-only formats like %d.%m.%Y
+        NaiveDate::parse_from_str(value, format)
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .ok_or(InnerParseError::from(e))
+    })?;
+    make_py_datetime(datetime, datetime, None, clamp_year)
+}
+
+/// Parses RFC 2822 and HTTP IMF-fixdate strings like `Tue, 15 Nov 1994 08:12:31 GMT`.
+pub fn parse_rfc2822_datetime(value: &str, clamp_year: bool) -> PyResult<*mut PyObject> {
+    let datetime = DateTime::parse_from_rfc2822(value).map_err(InnerParseError::from)?;
+    let py_tz = py_timezone_from_fixed_offset(datetime.offset().fix())?;
+    make_py_datetime(datetime, datetime, Some(py_tz), clamp_year)
+}
+
+pub fn datetime_from_timestamp(value: f64, clamp_year: bool) -> PyResult<*mut PyObject> {
+    let micros = (value * MICROS_PER_SECOND as f64).round();
+    let datetime = match micros.is_finite() && micros.abs() < i64::MAX as f64 {
+        true => NaiveDateTime::from_timestamp_opt(
+            (micros as i64).div_euclid(MICROS_PER_SECOND as i64),
+            ((micros as i64).rem_euclid(MICROS_PER_SECOND as i64) * 1000) as u32,
+        ),
+        false => None,
+    }
+    .ok_or_else(|| ValidationError::new_err(format!("Timestamp {} is out of range", value)))?;
+    let py_tz = py_timezone_from_fixed_offset(Utc.fix())?;
+    make_py_datetime(datetime, datetime, Some(py_tz), clamp_year)
+}
+
+/// Seconds since the epoch, naive datetimes are treated as UTC.
+pub fn datetime_to_timestamp(value: *mut PyObject, utc_offset: *mut PyObject) -> PyResult<f64> {
+    ensure_datetime_api();
+    if unsafe { PyDateTime_Check(value) } == 0 {
+        return Err(TypeMismatchError::new_err("Value is not a datetime"));
+    }
+    let datetime = unsafe {
+        NaiveDate::from_ymd_opt(
+            PyDateTime_GET_YEAR(value),
+            PyDateTime_GET_MONTH(value) as u32,
+            PyDateTime_GET_DAY(value) as u32,
+        )
+        .and_then(|date| {
+            date.and_hms_micro_opt(
+                PyDateTime_DATE_GET_HOUR(value) as u32,
+                PyDateTime_DATE_GET_MINUTE(value) as u32,
+                PyDateTime_DATE_GET_SECOND(value) as u32,
+                PyDateTime_DATE_GET_MICROSECOND(value) as u32,
+            )
+        })
+    }
+    .ok_or_else(|| ValidationError::new_err("Value is not a valid datetime"))?;
+    let mut micros = datetime.timestamp() as i128 * MICROS_PER_SECOND
+        + datetime.timestamp_subsec_micros() as i128;
+    if utc_offset != unsafe { NONE_PY_TYPE } {
+        micros -= duration_to_micros(utc_offset)?;
+    }
+    Ok(micros as f64 / MICROS_PER_SECOND as f64)
+}
+
 fn make_py_datetime(
     date: impl Datelike,
     time: impl Timelike,
     tz: Option<*mut PyObject>,
+    clamp_year: bool,
 ) -> PyResult<*mut PyObject> {
     let (micros, fold) = chrono_to_micros_and_fold(&time);
+    let (year, month, day, hour, minute, second, micros, fold) =
+        match check_year_range(date.year(), clamp_year)? {
+            Some(MIN_YEAR) => (MIN_YEAR, 1, 1, 0, 0, 0, 0, false),
+            Some(_) => (MAX_YEAR, 12, 31, 23, 59, 59, 999_999, false),
+            None => (
+                date.year(),
+                date.month(),
+                date.day(),
+                time.hour(),
+                time.minute(),
+                time.second(),
+                micros,
+                fold,
+            ),
+        };
     let api = ensure_datetime_api();
     let ptr = unsafe {
         (api.DateTime_FromDateAndTimeAndFold)(
-            date.year(),
-            c_int::from(date.month() as u8),
-            c_int::from(date.day() as u8),
-            c_int::from(time.hour() as u8),
-            c_int::from(time.minute() as u8),
-            c_int::from(time.second() as u8),
+            year,
+            c_int::from(month as u8),
+            c_int::from(day as u8),
+            c_int::from(hour as u8),
+            c_int::from(minute as u8),
+            c_int::from(second as u8),
             micros as c_int,
             tz.unwrap_or(NONE_PY_TYPE),
             c_int::from(fold),
@@ -96,6 +317,200 @@ fn make_py_datetime(
     from_ptr_or_err(ptr)
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum DurationFormat {
+    Iso8601,
+    Seconds,
+}
+
+impl DurationFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "iso8601" => Some(Self::Iso8601),
+            "seconds" => Some(Self::Seconds),
+            _ => None,
+        }
+    }
+}
+
+const MICROS_PER_SECOND: i128 = 1_000_000;
+const MICROS_PER_MINUTE: i128 = 60 * MICROS_PER_SECOND;
+const MICROS_PER_HOUR: i128 = 60 * MICROS_PER_MINUTE;
+const MICROS_PER_DAY: i128 = 24 * MICROS_PER_HOUR;
+const MICROS_PER_WEEK: i128 = 7 * MICROS_PER_DAY;
+
+/// Parses ISO 8601 durations like `P1DT2H30M` or `-PT0.5S`.
+/// Years and months are rejected, they have no fixed length.
+pub fn parse_duration(value: &str) -> PyResult<*mut PyObject> {
+    let micros = parse_iso_duration(value)
+        .ok_or_else(|| ValidationError::new_err(format!("Fail parse duration {:?}", value)))?;
+    make_py_delta(micros)
+}
+
+pub fn duration_from_seconds(value: f64) -> PyResult<*mut PyObject> {
+    let micros = (value * MICROS_PER_SECOND as f64).round();
+    if !micros.is_finite() || micros.abs() >= i64::MAX as f64 {
+        return Err(ValidationError::new_err(format!(
+            "Fail parse duration {:?}",
+            value
+        )));
+    }
+    make_py_delta(micros as i128)
+}
+
+pub fn duration_to_micros(value: *mut PyObject) -> PyResult<i128> {
+    ensure_datetime_api();
+    unsafe {
+        if PyDelta_Check(value) == 0 {
+            return Err(TypeMismatchError::new_err("Value is not a timedelta"));
+        }
+        Ok(PyDateTime_DELTA_GET_DAYS(value) as i128 * MICROS_PER_DAY
+            + PyDateTime_DELTA_GET_SECONDS(value) as i128 * MICROS_PER_SECOND
+            + PyDateTime_DELTA_GET_MICROSECONDS(value) as i128)
+    }
+}
+
+/// Appends `micros` as an ISO 8601 duration to `out`.
+pub fn format_duration(micros: i128, out: &mut String) {
+    let sign = if micros < 0 { "-" } else { "" };
+    let micros = micros.abs();
+    let days = micros / MICROS_PER_DAY;
+    let hours = micros % MICROS_PER_DAY / MICROS_PER_HOUR;
+    let minutes = micros % MICROS_PER_HOUR / MICROS_PER_MINUTE;
+    let seconds = micros % MICROS_PER_MINUTE / MICROS_PER_SECOND;
+    let fraction = micros % MICROS_PER_SECOND;
+
+    // writing to a String can't fail
+    let _ = write!(out, "{}P", sign);
+    if days > 0 {
+        let _ = write!(out, "{}D", days);
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || fraction > 0 || days == 0 {
+        out.push('T');
+        if hours > 0 {
+            let _ = write!(out, "{}H", hours);
+        }
+        if minutes > 0 {
+            let _ = write!(out, "{}M", minutes);
+        }
+        if fraction > 0 {
+            let _ = write!(out, "{}.{:06}", seconds, fraction);
+            let trimmed_len = out.trim_end_matches('0').len();
+            out.truncate(trimmed_len);
+            out.push('S');
+        } else if seconds > 0 || (hours == 0 && minutes == 0) {
+            let _ = write!(out, "{}S", seconds);
+        }
+    }
+}
+
+fn parse_iso_duration(value: &str) -> Option<i128> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let value = value.strip_prefix('P')?;
+    let (date_part, time_part) = match value.split_once('T') {
+        Some((date_part, time_part)) if !time_part.is_empty() => (date_part, Some(time_part)),
+        Some(_) => return None,
+        None if !value.is_empty() => (value, None),
+        None => return None,
+    };
+
+    let mut total: i128 = 0;
+    for (number, unit) in duration_components(date_part, &['W', 'D'])? {
+        let multiplier = if unit == 'W' {
+            MICROS_PER_WEEK
+        } else {
+            MICROS_PER_DAY
+        };
+        total = total.checked_add(parse_duration_int(number)?.checked_mul(multiplier)?)?;
+    }
+    for (number, unit) in duration_components(time_part.unwrap_or(""), &['H', 'M', 'S'])? {
+        let micros = match unit {
+            'H' => parse_duration_int(number)?.checked_mul(MICROS_PER_HOUR)?,
+            'M' => parse_duration_int(number)?.checked_mul(MICROS_PER_MINUTE)?,
+            _ => parse_duration_seconds(number)?,
+        };
+        total = total.checked_add(micros)?;
+    }
+    Some(if negative { -total } else { total })
+}
+
+/// Splits `1H30M` into `[("1", 'H'), ("30", 'M')]`, units must follow the given order.
+fn duration_components<'a>(value: &'a str, units: &[char]) -> Option<Vec<(&'a str, char)>> {
+    let mut components = vec![];
+    let mut units = units.iter();
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        if c.is_ascii_digit() || c == '.' || c == ',' {
+            continue;
+        }
+        if i == start || !units.any(|&unit| unit == c) {
+            return None;
+        }
+        components.push((&value[start..i], c));
+        start = i + 1;
+    }
+    match start == value.len() {
+        true => Some(components),
+        false => None,
+    }
+}
+
+fn parse_duration_int(value: &str) -> Option<i128> {
+    match value.bytes().all(|b| b.is_ascii_digit()) {
+        true => value.parse().ok(),
+        false => None,
+    }
+}
+
+fn parse_duration_seconds(value: &str) -> Option<i128> {
+    let (seconds, fraction) = value.split_once(['.', ',']).unwrap_or((value, ""));
+    if fraction.len() > 6 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let fraction = format!("{:0<6}", fraction).parse::<i128>().ok()?;
+    parse_duration_int(seconds)?
+        .checked_mul(MICROS_PER_SECOND)?
+        .checked_add(fraction)
+}
+
+fn make_py_delta(micros: i128) -> PyResult<*mut PyObject> {
+    let days = c_int::try_from(micros.div_euclid(MICROS_PER_DAY))
+        .map_err(|_| ValidationError::new_err("Duration is out of range"))?;
+    let rest = micros.rem_euclid(MICROS_PER_DAY);
+    let api = ensure_datetime_api();
+    unsafe {
+        let ptr = (api.Delta_FromDelta)(
+            days,
+            (rest / MICROS_PER_SECOND) as c_int,
+            (rest % MICROS_PER_SECOND) as c_int,
+            true as c_int,
+            api.DeltaType,
+        );
+        from_ptr_or_err(ptr).map_err(|_| ValidationError::new_err("Duration is out of range"))
+    }
+}
+
+// Python's datetime.MINYEAR and datetime.MAXYEAR
+const MIN_YEAR: i32 = 1;
+const MAX_YEAR: i32 = 9999;
+
+/// Returns the bound of the range a year outside of it is clamped to, or fails if clamping is off.
+fn check_year_range(year: i32, clamp_year: bool) -> PyResult<Option<i32>> {
+    if (MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return Ok(None);
+    }
+    if clamp_year {
+        return Ok(Some(year.clamp(MIN_YEAR, MAX_YEAR)));
+    }
+    Err(ValidationError::new_err(format!(
+        "Year {} is out of range {}..{}",
+        year, MIN_YEAR, MAX_YEAR
+    )))
+}
+
 fn chrono_to_micros_and_fold(time: &impl Timelike) -> (u32, bool) {
     if let Some(folded_nanos) = time.nanosecond().checked_sub(1_000_000_000) {
         (folded_nanos / 1000, true)
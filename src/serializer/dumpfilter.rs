@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Dict keys from dotted paths like "address.zip", nested keys are children of the parent key.
+#[derive(Debug, Default)]
+pub struct KeyTree {
+    whole: bool,
+    children: HashMap<String, Rc<KeyTree>>,
+}
+
+impl KeyTree {
+    pub fn from_paths<'a>(paths: impl IntoIterator<Item = &'a str>) -> Self {
+        let paths: Vec<Vec<&str>> = paths
+            .into_iter()
+            .map(|path| path.split('.').collect())
+            .collect();
+        Self::from_keys(paths.iter().map(|keys| keys.as_slice()))
+    }
+
+    /// Paths are grouped by their first key, the rest of the keys make the child tree.
+    fn from_keys<'a>(paths: impl Iterator<Item = &'a [&'a str]>) -> Self {
+        let mut whole = false;
+        let mut groups: HashMap<&str, Vec<&[&str]>> = HashMap::new();
+        for keys in paths {
+            match keys.split_first() {
+                Some((key, rest)) => groups.entry(key).or_default().push(rest),
+                None => whole = true,
+            }
+        }
+        let children = groups
+            .into_iter()
+            .map(|(key, rest)| (key.to_string(), Rc::new(Self::from_keys(rest.into_iter()))))
+            .collect();
+        KeyTree { whole, children }
+    }
+}
+
+thread_local! {
+    static DUMP_FILTER: RefCell<DumpFilter> = RefCell::new(DumpFilter::default());
+}
+
+/// include/exclude of `Serializer.dump` relative to the entity being dumped.
+#[derive(Debug, Clone, Default)]
+pub struct DumpFilter {
+    pub include: Option<Rc<KeyTree>>,
+    pub exclude: Option<Rc<KeyTree>>,
+}
+
+impl DumpFilter {
+    /// Runs `f` with the current filter, it's moved out while `f` runs instead of cloned.
+    /// Fields dumped with a filter of their own make it current with `with`.
+    pub fn with_current<T>(f: impl FnOnce(&DumpFilter) -> T) -> T {
+        let filter = DUMP_FILTER.with(|filter| filter.take());
+        let result = f(&filter);
+        DUMP_FILTER.with(|current| current.replace(filter));
+        result
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.include.is_none() && self.exclude.is_none()
+    }
+
+    /// Makes the filter current while `f` runs.
+    pub fn with<T>(self, f: impl FnOnce() -> T) -> T {
+        let previous = DUMP_FILTER.with(|filter| filter.replace(self));
+        let result = f();
+        DUMP_FILTER.with(|filter| filter.replace(previous));
+        result
+    }
+
+    /// Upper bound of the dumped fields, only included fields are dumped.
+    pub fn size_hint(&self, fields: usize) -> usize {
+        match &self.include {
+            Some(tree) => tree.children.len().min(fields),
+            None => fields,
+        }
+    }
+
+    /// Returns the filter for the field value, or None if the field is left out.
+    pub fn field(&self, key: &str) -> Option<DumpFilter> {
+        let include = match &self.include {
+            None => None,
+            Some(tree) => match tree.children.get(key) {
+                None => return None,
+                Some(node) if node.whole => None,
+                Some(node) => Some(node.clone()),
+            },
+        };
+        let exclude = match &self.exclude {
+            None => None,
+            Some(tree) => match tree.children.get(key) {
+                Some(node) if node.whole => return None,
+                node => node.cloned(),
+            },
+        };
+        Some(DumpFilter { include, exclude })
+    }
+}
@@ -0,0 +1,47 @@
+use std::cell::RefCell;
+
+use pyo3::{PyErr, PyResult, Python};
+
+use super::encoders::ValidationError;
+
+thread_local! {
+    // attribute names and indexes of the value that failed to dump, innermost first
+    static DUMP_PATH: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+/// Records the attribute name or index of the value that failed to dump.
+#[inline]
+pub fn with_dump_path(e: PyErr, key: impl FnOnce() -> String) -> PyErr {
+    DUMP_PATH.with(|path| path.borrow_mut().push(key()));
+    e
+}
+
+/// Adds the path of the value that failed to dump to the error.
+/// ValidationError messages get the path appended, other errors get a note on Python 3.11+.
+pub fn with_error_path<T>(f: impl FnOnce() -> PyResult<T>) -> PyResult<T> {
+    let previous = DUMP_PATH.with(|path| path.take());
+    let result = f();
+    let mut path = DUMP_PATH.with(|path| path.replace(previous));
+    result.map_err(|e| {
+        if path.is_empty() {
+            return e;
+        }
+        path.reverse();
+        let path = path.join("/");
+        Python::with_gil(|py| {
+            if e.is_instance_of::<ValidationError>(py) {
+                let message = format!("{} (at {:?})", e.value(py), path);
+                let error = PyErr::from_type(e.get_type(py), message);
+                error.set_cause(py, Some(e));
+                return error;
+            }
+            let value = e.value(py);
+            if value.hasattr("add_note").unwrap_or(false) {
+                let note = format!("while dumping the value at {:?}", path);
+                // the original error is more useful than a failure to add the note
+                let _ = value.call_method1("add_note", (note,));
+            }
+            e
+        })
+    })
+}
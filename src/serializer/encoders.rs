@@ -1,16 +1,51 @@
-use crate::serializer::dateutil::{parse_date, parse_time};
+use crate::serializer::bytesutil::BytesEncoding;
+use crate::serializer::cancel::{CancellationToken, Limits};
+use crate::serializer::currency::validate_currency;
+use crate::serializer::dateutil::{
+    datetime_from_timestamp, datetime_to_timestamp, duration_from_seconds, duration_to_micros,
+    format_duration, parse_date, parse_datetime_with_format, parse_duration,
+    parse_rfc2822_datetime, parse_time, DateTimeFormat, DurationFormat, SubsecondPrecision,
+};
+use crate::serializer::dumpfilter::{DumpFilter, KeyTree};
+use crate::serializer::dumppath::{with_dump_path, with_error_path};
+use crate::serializer::iputil::{validate_ip, IpKind, IpVersion};
+use crate::serializer::localeutil::CodeFormat;
+use crate::serializer::options::{LoadOptions, PathKey};
+use crate::serializer::panics::catch_panics;
+#[cfg(feature = "phone")]
+use crate::serializer::phoneutil::normalize_phone;
+use crate::serializer::presize::{check_size_hint, new_dict};
 use crate::serializer::py::{
-    create_new_object, from_ptr_or_err, iter_over_dict_items, obj_to_str, py_len,
-    py_object_call1_make_tuple_or_err, py_object_get_attr, py_object_get_item, py_object_set_attr,
-    py_str_to_str, py_tuple_get_item, to_decimal,
+    create_new_object, error_on_minusone, from_ptr_or_err, iter_over_dict_items, obj_to_str,
+    py_bytes_from_slice, py_bytes_to_slice, py_float_as_f64, py_float_from_f64, py_int_from_i64,
+    py_int_from_str, py_len, py_number_remainder, py_object_call1_make_tuple_or_err,
+    py_object_get_attr, py_object_get_item, py_object_hash, py_object_is_true,
+    py_object_rich_compare, py_object_set_attr, py_object_to_string, py_slot_get, py_slot_set,
+    py_str_from_str, py_str_to_str, py_tuple_get_item, to_decimal, to_iter,
+};
+use crate::serializer::scratch::py_str_with_buffer;
+use crate::serializer::semverutil::Version;
+use crate::serializer::textutil::closest_match;
+use crate::serializer::types::{
+    AMOUNT_STR, CURRENCY_STR, DUMP_STR, GET_SECRET_VALUE_STR, HEX_STR, ISOFORMAT_STR,
+    IS_ABSOLUTE_STR, JSON_DUMPS, JSON_LOADS, LOAD_STR, NAME_STR, NONE_PY_TYPE, PARTS_STR,
+    STRFTIME_STR, URN_STR, UTCOFFSET_STR, UUID_PY_TYPE, VALUE_STR,
 };
-use crate::serializer::types::{ISOFORMAT_STR, NONE_PY_TYPE, UUID_PY_TYPE, VALUE_STR};
+use crate::serializer::unset::UNSET;
 use atomic_refcell::AtomicRefCell;
-use pyo3::exceptions::{PyException, PyRuntimeError};
-use pyo3::types::{PyString, PyTuple};
-use pyo3::{pyclass, pymethods, AsPyPointer, Py, PyAny, PyResult, Python};
-use pyo3_ffi::PyObject;
+use pyo3::exceptions::{PyArithmeticError, PyException, PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{IntoPyDict, PyBytes, PyDict, PyFrozenSet, PyList, PyString, PyTuple};
+use pyo3::{
+    pyclass, pymethods, AsPyPointer, IntoPy, IntoPyPointer, Py, PyAny, PyErr, PyRef, PyResult,
+    PyTraverseError, PyVisit, Python,
+};
+use pyo3_ffi::{PyObject, Py_hash_t};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use super::dateutil::parse_datetime;
@@ -19,12 +54,29 @@ use super::macros::{call_method, call_object, ffi};
 use dyn_clone::{clone_trait_object, DynClone};
 
 pyo3::create_exception!(serpyco_rs, ValidationError, PyException);
+pyo3::create_exception!(serpyco_rs, RequiredFieldError, ValidationError);
+pyo3::create_exception!(serpyco_rs, TypeMismatchError, ValidationError);
+pyo3::create_exception!(serpyco_rs, ConstraintError, ValidationError);
 
 pub type TEncoder = dyn Encoder + Send + Sync;
 
 pub trait Encoder: DynClone + Debug {
     fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject>;
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject>;
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject>;
+
+    /// Reports the python objects the encoder holds to the garbage collector, see `Serializer::__traverse__`.
+    /// Recursive types aren't traversed through LazyEncoder, they are kept alive by its Arc cycle anyway.
+    fn traverse(&self, _visit: &PyVisit) -> Result<(), PyTraverseError> {
+        Ok(())
+    }
+}
+
+#[inline]
+fn visit_optional<T>(visit: &PyVisit, obj: &Option<Py<T>>) -> Result<(), PyTraverseError> {
+    match obj {
+        Some(obj) => visit.call(obj),
+        None => Ok(()),
+    }
 }
 
 clone_trait_object!(Encoder);
@@ -37,215 +89,1597 @@ pub struct Serializer {
 
 #[pymethods]
 impl Serializer {
-    pub fn dump(&self, value: &PyAny) -> PyResult<Py<PyAny>> {
-        unsafe {
-            Ok(Py::from_borrowed_ptr(
-                value.py(),
-                self.encoder.dump(value.as_ptr())?,
-            ))
+    #[args(include = "None", exclude = "None")]
+    pub fn dump(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let filter = DumpFilter {
+            include: key_tree("include", include)?,
+            exclude: key_tree("exclude", exclude)?,
+        };
+        let result = filter.with(|| {
+            with_error_path(|| {
+                catch_panics("Serializer.dump", || self.encoder.dump(value.as_ptr()))
+            })
+        })?;
+        unsafe { Ok(Py::from_borrowed_ptr(value.py(), result)) }
+    }
+    #[args(
+        trusted = "false",
+        cancel_token = "None",
+        max_duration_ms = "None",
+        partial = "false"
+    )]
+    pub fn load(
+        &self,
+        value: &PyAny,
+        trusted: bool,
+        cancel_token: Option<PyRef<CancellationToken>>,
+        max_duration_ms: Option<u64>,
+        partial: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let opts = LoadOptions {
+            trusted,
+            partial,
+            limits: Limits::new(cancel_token.as_deref(), max_duration_ms)?,
+            ..Default::default()
+        };
+        let result = catch_panics("Serializer.load", || {
+            self.encoder.load(value.as_ptr(), &opts)
+        })
+        .map_err(|e| match &opts.limits {
+            Some(limits) => limits.timeout_error(e),
+            None => e,
+        })
+        .map_err(|e| opts.field_error(e))?;
+        unsafe { Ok(Py::from_borrowed_ptr(value.py(), result)) }
+    }
+
+    // a Serializer stored on the class it refers to is a reference cycle
+    fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
+        self.encoder.traverse(&visit)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NoopEncoder;
+
+impl Encoder for NoopEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        Ok(value)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        Ok(value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BytesEncoder {
+    pub(crate) encoding: BytesEncoding,
+}
+
+impl Encoder for BytesEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let data = py_bytes_to_slice(value)?;
+        py_str_with_buffer(|buffer| self.encoding.encode(data, buffer))
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        py_bytes_from_slice(&self.encoding.decode(py_str_to_str(value)?)?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DecimalEncoder {
+    pub(crate) min: Option<Py<PyAny>>,
+    pub(crate) max: Option<Py<PyAny>>,
+    pub(crate) exclusive_min: Option<Py<PyAny>>,
+    pub(crate) exclusive_max: Option<Py<PyAny>>,
+    pub(crate) multiple_of: Option<Py<PyAny>>,
+    pub(crate) max_digits: Option<usize>,
+    pub(crate) places: Option<usize>,
+}
+
+impl DecimalEncoder {
+    fn check_digits(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<()> {
+        if (self.max_digits.is_none() && self.places.is_none()) || opts.trusted {
+            return Ok(());
+        }
+        Python::with_gil(|py| {
+            let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+            let decimal_tuple = value.call_method0("as_tuple")?;
+            let digits_count = decimal_tuple.getattr("digits")?.len()?;
+            let exponent: i64 = decimal_tuple.getattr("exponent")?.extract().map_err(|_| {
+                ValidationError::new_err(format!("{} is not a finite number", value))
+            })?;
+
+            // Leading zeros after the decimal point count as digits: Decimal("0.001") has 3
+            let (digits, decimals) = match exponent >= 0 {
+                true => (digits_count + exponent as usize, 0),
+                false => {
+                    let decimals = exponent.unsigned_abs() as usize;
+                    (digits_count.max(decimals), decimals)
+                }
+            };
+
+            if let Some(max_digits) = self.max_digits {
+                if digits > max_digits {
+                    return Err(ConstraintError::new_err(format!(
+                        "{} has more than {} digits in total",
+                        value, max_digits
+                    )));
+                }
+            }
+            if let Some(places) = self.places {
+                if decimals > places {
+                    return Err(ConstraintError::new_err(format!(
+                        "{} has more than {} decimal places",
+                        value, places
+                    )));
+                }
+            }
+            if let (Some(max_digits), Some(places)) = (self.max_digits, self.places) {
+                if digits - decimals > max_digits.saturating_sub(places) {
+                    return Err(ConstraintError::new_err(format!(
+                        "{} has more than {} digits before the decimal point",
+                        value,
+                        max_digits.saturating_sub(places)
+                    )));
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+impl Encoder for DecimalEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        obj_to_str(value)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        let result = to_decimal(value).map_err(|e| {
+            ValidationError::new_err(format!("invalid Decimal value: {:?} error: {:?}", value, e))
+        })?;
+        let checked = check_number_bounds(
+            result,
+            [
+                &self.min,
+                &self.max,
+                &self.exclusive_min,
+                &self.exclusive_max,
+            ],
+            opts,
+        )
+        .and_then(|_| self.check_digits(result, opts))
+        .and_then(|_| check_multiple_of(result, &self.multiple_of, opts));
+        if let Err(e) = checked {
+            ffi!(Py_DECREF(result));
+            return Err(e);
         }
+        Ok(result)
     }
-    pub fn load(&self, value: &PyAny) -> PyResult<Py<PyAny>> {
-        unsafe {
-            Ok(Py::from_borrowed_ptr(
-                value.py(),
-                self.encoder.load(value.as_ptr())?,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrefixedIntEncoder {
+    pub(crate) min: Option<Py<PyAny>>,
+    pub(crate) max: Option<Py<PyAny>>,
+    pub(crate) exclusive_min: Option<Py<PyAny>>,
+    pub(crate) exclusive_max: Option<Py<PyAny>>,
+    pub(crate) multiple_of: Option<Py<PyAny>>,
+}
+
+impl Encoder for PrefixedIntEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        Ok(value)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        // ints are already checked by the schema, strings are checked after conversion
+        if ffi!(PyUnicode_Check(value)) == 0 {
+            return Ok(value);
+        }
+        // base 0 accepts the same "0x", "0o" and "0b" prefixes as python literals
+        let result = py_int_from_str(value, 0).map_err(|_| {
+            ValidationError::new_err(format!(
+                "{:?} is not a valid integer",
+                py_object_to_string(value)
             ))
+        })?;
+        let checked = check_number_bounds(
+            result,
+            [
+                &self.min,
+                &self.max,
+                &self.exclusive_min,
+                &self.exclusive_max,
+            ],
+            opts,
+        )
+        .and_then(|_| check_multiple_of(result, &self.multiple_of, opts));
+        if let Err(e) = checked {
+            ffi!(Py_DECREF(result));
+            return Err(e);
         }
+        Ok(result)
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct NoopEncoder;
+pub struct DictionaryEncoder {
+    pub key_encoder: Box<TEncoder>,
+    pub value_encoder: Box<TEncoder>,
+    pub int_keys: bool,
+}
+
+impl DictionaryEncoder {
+    /// Returns a new reference, the key is released once it's set.
+    #[inline]
+    fn dump_key(&self, key: *mut PyObject) -> PyResult<*mut PyObject> {
+        let dumped = self.key_encoder.dump(key)?;
+        if ffi!(PyUnicode_Check(dumped)) != 0 {
+            // passthrough encoders return the borrowed key itself
+            if dumped == key {
+                ffi!(Py_INCREF(dumped));
+            }
+            return Ok(dumped);
+        }
+        let result = obj_to_str(dumped);
+        if dumped != key {
+            ffi!(Py_DECREF(dumped));
+        }
+        result
+    }
+
+    #[inline]
+    fn dump_item(&self, dict_ptr: *mut PyObject, item: *mut PyObject) -> PyResult<()> {
+        let raw_key = py_tuple_get_item(item, 0)?;
+        let key = self.dump_key(raw_key)?;
+        let result = py_tuple_get_item(item, 1)
+            .and_then(|value| self.value_encoder.dump(value))
+            .map_err(|e| with_dump_path(e, || py_object_to_string(raw_key)))
+            .and_then(|value| error_on_minusone(ffi!(PyDict_SetItem(dict_ptr, key, value))));
+        ffi!(Py_DECREF(key));
+        result
+    }
+
+    #[inline]
+    fn load_key(&self, key: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        if self.int_keys && ffi!(PyUnicode_Check(key)) != 0 {
+            let int_key = py_int_from_str(key, 10).map_err(|_| {
+                ValidationError::new_err(format!(
+                    "{:?} is not a valid integer key",
+                    py_object_to_string(key)
+                ))
+            })?;
+            let result = self.key_encoder.load(int_key, opts);
+            if !matches!(result, Ok(loaded) if loaded == int_key) {
+                ffi!(Py_DECREF(int_key));
+            }
+            return result;
+        }
+        self.key_encoder.load(key, opts)
+    }
+
+    #[inline]
+    fn load_item(
+        &self,
+        dict_ptr: *mut PyObject,
+        item: *mut PyObject,
+        opts: &LoadOptions,
+    ) -> PyResult<()> {
+        let raw_key = py_tuple_get_item(item, 0)?;
+        let key = self.load_key(raw_key, opts)?;
+        let result = opts
+            .check_limits()
+            .and_then(|_| self.value_encoder.load(py_tuple_get_item(item, 1)?, opts))
+            .map_err(|e| opts.with_path(e, || PathKey::Key(py_object_to_string(raw_key))))
+            .and_then(|value| error_on_minusone(ffi!(PyDict_SetItem(dict_ptr, key, value))));
+        // a key returned as is is borrowed from the input
+        if key != raw_key {
+            ffi!(Py_DECREF(key));
+        }
+        result
+    }
+}
+
+impl Encoder for DictionaryEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let items = iter_over_dict_items(value)?;
+        let size_hint = py_len(value)? as usize;
+        let dict_ptr = new_dict(size_hint)?;
+
+        for i in items {
+            if let Err(e) = i.and_then(|item| self.dump_item(dict_ptr, item)) {
+                ffi!(Py_DECREF(dict_ptr));
+                return Err(e);
+            }
+        }
+        check_size_hint(dict_ptr, size_hint);
+
+        Ok(dict_ptr)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        let items = iter_over_dict_items(value)?;
+        let size_hint = py_len(value)? as usize;
+        let dict_ptr = new_dict(size_hint)?;
+
+        for i in items {
+            if let Err(e) = i.and_then(|item| self.load_item(dict_ptr, item, opts)) {
+                ffi!(Py_DECREF(dict_ptr));
+                return Err(e);
+            }
+        }
+        check_size_hint(dict_ptr, size_hint);
+
+        Ok(dict_ptr)
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        self.key_encoder.traverse(visit)?;
+        self.value_encoder.traverse(visit)
+    }
+}
+
+/// Makes a loaded or dumped value a new reference, passthrough encoders return the borrowed item itself.
+#[inline]
+fn own_item(item: *mut PyObject, val: *mut PyObject) -> *mut PyObject {
+    if val == item {
+        ffi!(Py_INCREF(item));
+    }
+    val
+}
+
+#[derive(Debug, Clone)]
+pub struct ArrayEncoder {
+    pub encoder: Box<TEncoder>,
+    pub unique_items: bool,
+    pub unique_by: Option<Py<PyString>>,
+    pub sorted_by: Option<(Py<PyString>, bool)>,
+}
+
+impl ArrayEncoder {
+    fn has_item_checks(&self, opts: &LoadOptions) -> bool {
+        !opts.trusted && (self.unique_items || self.unique_by.is_some() || self.sorted_by.is_some())
+    }
+
+    fn check_items(&self, list: *mut PyObject, len: isize) -> PyResult<()> {
+        if self.unique_items {
+            with_item_keys(list, len, None, |keys| find_duplicate(keys, None))?;
+        }
+        if let Some(field) = &self.unique_by {
+            with_item_keys(list, len, Some(field), |keys| {
+                find_duplicate(keys, Some(field))
+            })?;
+        }
+        if let Some((field, descending)) = &self.sorted_by {
+            with_item_keys(list, len, Some(field), |keys| {
+                check_sorted(keys, field, *descending)
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl Encoder for ArrayEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let len = py_len(value)?;
+
+        let list = ffi!(PyList_New(len));
+
+        for i in 0..len {
+            let item = ffi!(PyList_GetItem(value, i));
+            let val = self
+                .encoder
+                .dump(item)
+                .map_err(|e| with_dump_path(e, || i.to_string()))?;
+
+            ffi!(PyList_SetItem(list, i, own_item(item, val)));
+        }
+
+        Ok(list)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        let len = py_len(value)?;
+        let list = ffi!(PyList_New(len));
+        for i in 0..len {
+            let item = ffi!(PyList_GetItem(value, i));
+            let val = opts
+                .check_limits()
+                .and_then(|_| self.encoder.load(item, opts))
+                .map_err(|e| opts.with_path(e, || PathKey::Index(i)))?;
+            ffi!(PyList_SetItem(list, i, own_item(item, val)));
+        }
+        if self.has_item_checks(opts) {
+            self.check_items(list, len)?;
+        }
+        Ok(list)
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        self.encoder.traverse(visit)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SetEncoder {
+    pub(crate) encoder: Box<TEncoder>,
+    pub(crate) is_frozen: bool,
+    pub(crate) unique_items: bool,
+}
+
+impl SetEncoder {
+    // passthrough encoders return the item itself, which is the reference the iterator gave us
+    #[inline]
+    fn release_item(item: *mut PyObject, val: *mut PyObject) {
+        if val != item {
+            ffi!(Py_DECREF(item));
+        }
+    }
+
+    fn load_items(
+        &self,
+        value: *mut PyObject,
+        opts: &LoadOptions,
+        items: &mut Vec<*mut PyObject>,
+    ) -> PyResult<()> {
+        for item in to_iter(value)? {
+            let item = item?;
+            let val = opts
+                .check_limits()
+                .and_then(|_| self.encoder.load(item, opts));
+            match val {
+                Ok(val) => {
+                    Self::release_item(item, val);
+                    items.push(val);
+                }
+                Err(e) => {
+                    ffi!(Py_DECREF(item));
+                    return Err(e);
+                }
+            }
+        }
+        if self.unique_items && !opts.trusted {
+            find_duplicate(items, None)?;
+        }
+        Ok(())
+    }
+
+    fn new_set(&self, items: &[*mut PyObject]) -> PyResult<*mut PyObject> {
+        let set = from_ptr_or_err(ffi!(PySet_New(std::ptr::null_mut())))?;
+        for &item in items {
+            if let Err(e) = error_on_minusone(ffi!(PySet_Add(set, item))) {
+                ffi!(Py_DECREF(set));
+                return Err(e);
+            }
+        }
+        if self.is_frozen {
+            let frozen_set = from_ptr_or_err(ffi!(PyFrozenSet_New(set)));
+            ffi!(Py_DECREF(set));
+            return frozen_set;
+        }
+        Ok(set)
+    }
+}
+
+impl Encoder for SetEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let len = py_len(value)?;
+        let list = ffi!(PyList_New(len));
+        // the iterator goes first so it's run to the end, which releases its reference to the set
+        for (item, i) in to_iter(value)?.zip(0..len) {
+            let result = item.and_then(|item| {
+                let val = self.encoder.dump(item);
+                match val {
+                    Ok(val) => Self::release_item(item, val),
+                    Err(_) => ffi!(Py_DECREF(item)),
+                }
+                val
+            });
+            match result {
+                Ok(val) => ffi!(PyList_SetItem(list, i, val)),
+                Err(e) => {
+                    ffi!(Py_DECREF(list));
+                    return Err(with_dump_path(e, || i.to_string()));
+                }
+            };
+        }
+        Ok(list)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        // the loaded items are kept in order, so a duplicate is reported by its input index
+        let mut items = vec![];
+        let result = self
+            .load_items(value, opts, &mut items)
+            .and_then(|_| self.new_set(&items));
+        for item in items {
+            ffi!(Py_DECREF(item));
+        }
+        result
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        self.encoder.traverse(visit)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EntityEncoder {
+    pub(crate) create_new_object_args: Py<PyTuple>,
+    pub(crate) fields: Vec<Field>,
+    // load keys and aliases of the fields, the other keys are collected by an ExtraKeys field
+    pub(crate) consumed_keys: Py<PyFrozenSet>,
+    pub(crate) post_load: Option<Py<PyString>>,
+    pub(crate) pre_dump: Option<Py<PyAny>>,
+    // load calls the class with the fields as keyword arguments instead of setting attributes
+    pub(crate) use_init: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub(crate) name: Py<PyString>,
+    pub(crate) load_key: Py<PyString>,
+    pub(crate) dump_key: Py<PyString>,
+    pub(crate) aliases: Vec<Py<PyString>>,
+    pub(crate) encoder: Box<TEncoder>,
+    pub(crate) default: Option<Py<PyAny>>,
+    pub(crate) default_factory: Option<Py<PyAny>>,
+    pub(crate) skip_if_default: bool,
+    pub(crate) skip_if: Option<Py<PyAny>>,
+    // properties are dump only
+    pub(crate) is_property: bool,
+    // a dict of the input keys not consumed by the other fields
+    pub(crate) is_extra_keys: bool,
+    // not an argument of the class __init__
+    pub(crate) init: bool,
+    pub(crate) init_name: Py<PyString>,
+    // member descriptor of a __slots__ attribute
+    pub(crate) slot: Option<Py<PyAny>>,
+    // ErrorMessage template, replaces the message of a ValidationError raised by the value
+    pub(crate) error_message: Option<Py<PyString>>,
+}
+
+impl Field {
+    /// Looks up `load_key`, then the aliases in order.
+    #[inline]
+    fn get_value(&self, data: *mut PyObject) -> PyResult<*mut PyObject> {
+        match py_object_get_item(data, self.load_key.as_ptr()) {
+            Err(e) => self
+                .aliases
+                .iter()
+                .find_map(|alias| py_object_get_item(data, alias.as_ptr()).ok())
+                .ok_or(e),
+            value => value,
+        }
+    }
+
+    #[inline]
+    fn skip_dump(&self, value: *mut PyObject) -> PyResult<bool> {
+        if self.skip_if_default {
+            let is_default = match (&self.default, &self.default_factory) {
+                (Some(default), _) => {
+                    py_object_rich_compare(value, default.as_ptr(), CompareOp::Eq)?
+                }
+                (_, Some(factory)) => {
+                    let default = call_object!(factory.as_ptr())?;
+                    let result = py_object_rich_compare(value, default, CompareOp::Eq);
+                    ffi!(Py_DECREF(default));
+                    result?
+                }
+                (None, None) => false,
+            };
+            if is_default {
+                return Ok(true);
+            }
+        }
+        match &self.skip_if {
+            Some(predicate) => {
+                let result = py_object_call1_make_tuple_or_err(predicate.as_ptr(), value)?;
+                let skip = py_object_is_true(result);
+                ffi!(Py_DECREF(result));
+                skip
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+impl EntityEncoder {
+    /// Loads the field values from the data dict and passes them to `set` as new references, it releases them.
+    fn load_fields(
+        &self,
+        py: Python<'_>,
+        value: *mut PyObject,
+        opts: &LoadOptions,
+        mut set: impl FnMut(&Field, *mut PyObject) -> PyResult<()>,
+    ) -> PyResult<()> {
+        for field in &self.fields {
+            if field.is_property || (self.use_init && !field.init) {
+                continue;
+            }
+            if field.is_extra_keys {
+                set(field, self.load_extra_keys(py, field, value, opts)?)?;
+                continue;
+            }
+            let val = match field.get_value(value) {
+                Ok(val) => {
+                    let result = opts
+                        .check_limits()
+                        .and_then(|_| field.encoder.load(val, opts))
+                        .map_err(|e| {
+                            opts.with_field_path(
+                                e,
+                                &field.load_key,
+                                field.error_message.as_ref(),
+                                val,
+                            )
+                        });
+                    // the value read from the data is a new reference, passthrough encoders hand it on
+                    if !matches!(result, Ok(loaded) if loaded == val) {
+                        ffi!(Py_DECREF(val));
+                    }
+                    result?
+                }
+                Err(_) if opts.partial => {
+                    let unset = unsafe { UNSET };
+                    ffi!(Py_INCREF(unset));
+                    unset
+                }
+                Err(e) => match (&field.default, &field.default_factory) {
+                    (Some(val), _) => val.clone_ref(py).into_ptr(),
+                    (_, Some(val)) => call_object!(val.as_ptr())?,
+                    (None, _) => {
+                        return Err(RequiredFieldError::new_err(format!(
+                            "data dictionary is missing required parameter {} (err: {})",
+                            &field.name, e
+                        )))
+                    }
+                },
+            };
+            set(field, val)?
+        }
+        Ok(())
+    }
+
+    fn load_extra_keys(
+        &self,
+        py: Python<'_>,
+        field: &Field,
+        value: *mut PyObject,
+        opts: &LoadOptions,
+    ) -> PyResult<*mut PyObject> {
+        let data: &PyDict = unsafe { py.from_borrowed_ptr::<PyAny>(value) }
+            .downcast()
+            .map_err(|_| {
+                TypeMismatchError::new_err("extra keys can only be collected from a dict")
+            })?;
+        let consumed_keys = self.consumed_keys.as_ref(py);
+        let extra = PyDict::new(py);
+        for (key, val) in data {
+            if !consumed_keys.contains(key)? {
+                extra.set_item(key, val)?;
+            }
+        }
+        let loaded = field.encoder.load(extra.as_ptr(), opts)?;
+        Ok(own_item(extra.as_ptr(), loaded))
+    }
+
+    /// Adds the dumped extra keys to the entity dict, declared fields take precedence.
+    fn dump_extra_keys(
+        &self,
+        field: &Field,
+        value: *mut PyObject,
+        dict_ptr: *mut PyObject,
+        filter: &DumpFilter,
+    ) -> PyResult<()> {
+        let dumped = field.encoder.dump(value)?;
+        Python::with_gil(|py| {
+            let dumped: &PyDict = unsafe { py.from_owned_ptr::<PyAny>(dumped) }.downcast()?;
+            let dict: &PyDict = unsafe { py.from_borrowed_ptr::<PyAny>(dict_ptr) }.downcast()?;
+            for (key, val) in dumped {
+                if dict.contains(key)? {
+                    continue;
+                }
+                if !filter.is_empty() && filter.field(key.extract()?).is_none() {
+                    continue;
+                }
+                dict.set_item(key, val)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn dump_fields(&self, value: *mut PyObject, filter: &DumpFilter) -> PyResult<*mut PyObject> {
+        let size_hint = filter.size_hint(self.fields.len());
+        let dict_ptr = new_dict(size_hint)?;
+
+        for field in &self.fields {
+            let field_val = match &field.slot {
+                Some(slot) => py_slot_get(slot.as_ptr(), value)?,
+                None => py_object_get_attr(value, field.name.as_ptr())?,
+            };
+            if field_val == unsafe { UNSET } || field.skip_dump(field_val)? {
+                continue;
+            }
+            if field.is_extra_keys {
+                self.dump_extra_keys(field, field_val, dict_ptr, filter)?;
+                continue;
+            }
+            let dump_result = match filter.is_empty() {
+                true => field.encoder.dump(field_val),
+                false => match filter.field(py_str_to_str(field.dump_key.as_ptr())?) {
+                    Some(field_filter) => field_filter.with(|| field.encoder.dump(field_val)),
+                    None => continue,
+                },
+            }
+            .map_err(|e| with_dump_path(e, || field.name.to_string()))?;
+            ffi!(PyDict_SetItem(
+                dict_ptr,
+                field.dump_key.as_ptr(),
+                dump_result
+            ));
+        }
+        check_size_hint(dict_ptr, size_hint);
+        Ok(dict_ptr)
+    }
+}
+
+impl Encoder for EntityEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let value = match &self.pre_dump {
+            Some(hook) => py_object_call1_make_tuple_or_err(hook.as_ptr(), value)?,
+            None => value,
+        };
+        let result = DumpFilter::with_current(|filter| self.dump_fields(value, filter));
+        if self.pre_dump.is_some() {
+            ffi!(Py_DECREF(value));
+        }
+        result
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        opts.check_limits_now()?;
+        Python::with_gil(|py| {
+            let obj = match self.use_init {
+                true => {
+                    let kwargs = PyDict::new(py);
+                    self.load_fields(py, value, opts, |field, val| {
+                        let val = unsafe { py.from_owned_ptr::<PyAny>(val) };
+                        kwargs.set_item(field.init_name.as_ref(py), val)
+                    })?;
+                    let cls = self.create_new_object_args.as_ref(py).get_item(0)?;
+                    cls.call((), Some(kwargs))
+                        .map_err(|e| value_error_to_validation_error(py, e))?
+                        .into_ptr()
+                }
+                false => {
+                    let obj = create_new_object(self.create_new_object_args.as_ref(py))?;
+                    self.load_fields(py, value, opts, |field, val| {
+                        let result = match &field.slot {
+                            Some(slot) => py_slot_set(slot.as_ptr(), obj, val),
+                            None => py_object_set_attr(obj, field.name.as_ptr(), val),
+                        };
+                        ffi!(Py_DECREF(val));
+                        result
+                    })?;
+                    obj
+                }
+            };
+            if let Some(method) = &self.post_load {
+                let result = call_method!(obj, method.as_ptr())
+                    .map_err(|e| value_error_to_validation_error(py, e))?;
+                ffi!(Py_DECREF(result));
+            }
+            Ok(obj)
+        })
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        visit.call(&self.create_new_object_args)?;
+        visit_optional(visit, &self.pre_dump)?;
+        for field in &self.fields {
+            field.encoder.traverse(visit)?;
+            visit_optional(visit, &field.default)?;
+            visit_optional(visit, &field.default_factory)?;
+            visit_optional(visit, &field.skip_if)?;
+            // member descriptors reference their class
+            visit_optional(visit, &field.slot)?;
+        }
+        Ok(())
+    }
+}
+
+/// Entity of up to 8 passthrough fields (plain int, float, bool and str) without defaults, aliases,
+/// slots or hooks. The values are copied as is, no field encoder is called, see `get_encoder`.
+#[derive(Debug, Clone)]
+pub struct SmallEntityEncoder<const N: usize> {
+    pub(crate) create_new_object_args: Py<PyTuple>,
+    pub(crate) fields: [ScalarField; N],
+}
+
+#[derive(Debug, Clone)]
+pub struct ScalarField {
+    pub(crate) name: Py<PyString>,
+    pub(crate) load_key: Py<PyString>,
+    pub(crate) dump_key: Py<PyString>,
+}
+
+impl<const N: usize> SmallEntityEncoder<N> {
+    #[inline]
+    fn dump_field(
+        field: &ScalarField,
+        value: *mut PyObject,
+        dict_ptr: *mut PyObject,
+        filter: &DumpFilter,
+    ) -> PyResult<()> {
+        if !filter.is_empty()
+            && filter
+                .field(py_str_to_str(field.dump_key.as_ptr())?)
+                .is_none()
+        {
+            return Ok(());
+        }
+        let field_val = py_object_get_attr(value, field.name.as_ptr())?;
+        let result = match field_val == unsafe { UNSET } {
+            true => Ok(()),
+            false => error_on_minusone(ffi!(PyDict_SetItem(
+                dict_ptr,
+                field.dump_key.as_ptr(),
+                field_val
+            ))),
+        };
+        ffi!(Py_DECREF(field_val));
+        result
+    }
+
+    #[inline]
+    fn load_field(
+        field: &ScalarField,
+        obj: *mut PyObject,
+        value: *mut PyObject,
+        opts: &LoadOptions,
+    ) -> PyResult<()> {
+        opts.check_limits()
+            .map_err(|e| opts.with_path(e, || PathKey::Key(field.load_key.to_string())))?;
+        let val = match py_object_get_item(value, field.load_key.as_ptr()) {
+            Ok(val) => val,
+            Err(_) if opts.partial => {
+                let unset = unsafe { UNSET };
+                ffi!(Py_INCREF(unset));
+                unset
+            }
+            Err(e) => {
+                return Err(RequiredFieldError::new_err(format!(
+                    "data dictionary is missing required parameter {} (err: {})",
+                    &field.name, e
+                )))
+            }
+        };
+        let result = py_object_set_attr(obj, field.name.as_ptr(), val);
+        ffi!(Py_DECREF(val));
+        result
+    }
+}
+
+impl<const N: usize> Encoder for SmallEntityEncoder<N> {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        DumpFilter::with_current(|filter| {
+            let size_hint = filter.size_hint(N);
+            let dict_ptr = new_dict(size_hint)?;
+            for field in &self.fields {
+                if let Err(e) = Self::dump_field(field, value, dict_ptr, filter) {
+                    ffi!(Py_DECREF(dict_ptr));
+                    return Err(e);
+                }
+            }
+            check_size_hint(dict_ptr, size_hint);
+            Ok(dict_ptr)
+        })
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        opts.check_limits_now()?;
+        let obj = Python::with_gil(|py| create_new_object(self.create_new_object_args.as_ref(py)))?;
+        for field in &self.fields {
+            if let Err(e) = Self::load_field(field, obj, value, opts) {
+                ffi!(Py_DECREF(obj));
+                return Err(e);
+            }
+        }
+        Ok(obj)
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        visit.call(&self.create_new_object_args)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UUIDEncoder {
+    pub(crate) as_str: bool,
+    pub(crate) lenient: bool,
+    pub(crate) dump_format: UuidFormat,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum UuidFormat {
+    Canonical,
+    Hex,
+    Urn,
+    Uppercase,
+}
+
+impl UuidFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "canonical" => Some(Self::Canonical),
+            "hex" => Some(Self::Hex),
+            "urn" => Some(Self::Urn),
+            "uppercase" => Some(Self::Uppercase),
+            _ => None,
+        }
+    }
+}
+
+impl UUIDEncoder {
+    fn load_binary(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let kwarg = if ffi!(PyBytes_Check(value)) != 0 {
+            "bytes"
+        } else if ffi!(PyLong_Check(value)) != 0 && ffi!(PyBool_Check(value)) == 0 {
+            "int"
+        } else {
+            return Err(invalid_uuid(value));
+        };
+        let uuid = Python::with_gil(|py| {
+            let uuid_type = unsafe { py.from_borrowed_ptr::<PyAny>(UUID_PY_TYPE) };
+            let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+            let kwargs = [(kwarg, value)].into_py_dict(py);
+            uuid_type
+                .call((), Some(kwargs))
+                .map(|uuid| uuid.into_ptr())
+                .map_err(|_| invalid_uuid(value.as_ptr()))
+        })?;
+        if !self.as_str {
+            return Ok(uuid);
+        }
+        let result = obj_to_str(uuid);
+        ffi!(Py_DECREF(uuid));
+        result
+    }
+
+    #[inline]
+    fn canonicalize(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let uuid = py_object_call1_make_tuple_or_err(unsafe { UUID_PY_TYPE }, value)
+            .map_err(|_| invalid_uuid(value))?;
+        let result = obj_to_str(uuid);
+        ffi!(Py_DECREF(uuid));
+        result
+    }
+
+    #[inline]
+    fn format(&self, uuid: *mut PyObject) -> PyResult<*mut PyObject> {
+        match self.dump_format {
+            UuidFormat::Canonical => obj_to_str(uuid),
+            UuidFormat::Hex => py_object_get_attr(uuid, unsafe { HEX_STR }),
+            UuidFormat::Urn => py_object_get_attr(uuid, unsafe { URN_STR }),
+            UuidFormat::Uppercase => {
+                let canonical = obj_to_str(uuid)?;
+                let result = py_str_to_str(canonical).and_then(|canonical| {
+                    py_str_with_buffer(|buffer| {
+                        buffer.push_str(canonical);
+                        buffer.make_ascii_uppercase();
+                    })
+                });
+                ffi!(Py_DECREF(canonical));
+                result
+            }
+        }
+    }
+}
+
+impl Encoder for UUIDEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        if !self.as_str {
+            return self.format(value);
+        }
+        let uuid = py_object_call1_make_tuple_or_err(unsafe { UUID_PY_TYPE }, value)
+            .map_err(|_| invalid_uuid(value))?;
+        let result = self.format(uuid);
+        ffi!(Py_DECREF(uuid));
+        result
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        if self.lenient && ffi!(PyUnicode_Check(value)) == 0 {
+            return self.load_binary(value);
+        }
+        match self.as_str {
+            true => self.canonicalize(value),
+            false => py_object_call1_make_tuple_or_err(unsafe { UUID_PY_TYPE }, value),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IpEncoder {
+    pub(crate) cls: pyo3::PyObject,
+    pub(crate) version: IpVersion,
+    pub(crate) kind: IpKind,
+}
+
+impl Encoder for IpEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        obj_to_str(value)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        validate_ip(py_str_to_str(value)?, self.version, self.kind)?;
+        py_object_call1_make_tuple_or_err(self.cls.as_ptr(), value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MoneyEncoder {
+    pub(crate) cls: pyo3::PyObject,
+}
+
+impl Encoder for MoneyEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let dict_ptr = new_dict(2)?;
+        let amount = py_object_get_attr(value, unsafe { AMOUNT_STR })?;
+        let amount_str = obj_to_str(amount)?;
+        let currency = py_object_get_attr(value, unsafe { CURRENCY_STR })?;
+        ffi!(PyDict_SetItem(dict_ptr, AMOUNT_STR, amount_str));
+        ffi!(PyDict_SetItem(dict_ptr, CURRENCY_STR, currency));
+        ffi!(Py_DECREF(amount));
+        ffi!(Py_DECREF(amount_str));
+        ffi!(Py_DECREF(currency));
+        Ok(dict_ptr)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        Python::with_gil(|py| {
+            // accepts {"amount": "12.34", "currency": "EUR"} or "12.34 EUR"
+            let (amount, currency) = if ffi!(PyUnicode_Check(value)) != 0 {
+                let data = py_str_to_str(value)?;
+                let (amount, currency) = data.split_once(' ').ok_or_else(|| {
+                    ValidationError::new_err(format!("{:?} is not a valid money value", data))
+                })?;
+                (
+                    PyString::new(py, amount).as_ref(),
+                    PyString::new(py, currency).as_ref(),
+                )
+            } else {
+                unsafe {
+                    (
+                        py.from_owned_ptr::<PyAny>(py_object_get_item(value, AMOUNT_STR)?),
+                        py.from_owned_ptr::<PyAny>(py_object_get_item(value, CURRENCY_STR)?),
+                    )
+                }
+            };
+            validate_currency(py_str_to_str(currency.as_ptr())?)?;
+            let invalid_amount = || {
+                ValidationError::new_err(format!(
+                    "{:?} is not a valid money amount",
+                    amount.to_string()
+                ))
+            };
+            // floats go through str() so 12.34 stays 12.34 instead of its binary expansion
+            let amount_str = unsafe { py.from_owned_ptr::<PyAny>(obj_to_str(amount.as_ptr())?) };
+            let decimal = to_decimal(amount_str.as_ptr()).map_err(|_| invalid_amount())?;
+            let decimal = unsafe { py.from_owned_ptr::<PyAny>(decimal) };
+            // Decimal("NaN") and Decimal("Infinity") parse, but aren't amounts
+            if !decimal.call_method0("is_finite")?.is_true()? {
+                return Err(invalid_amount());
+            }
+            Ok(self.cls.call1(py, (decimal, currency))?.into_ptr())
+        })
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        visit.call(&self.cls)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StringEncoder {
+    pub(crate) strip_whitespace: bool,
+    pub(crate) to_lower: bool,
+    pub(crate) to_upper: bool,
+    pub(crate) min_length: Option<usize>,
+    pub(crate) max_length: Option<usize>,
+    pub(crate) encoder: Box<TEncoder>,
+}
+
+impl StringEncoder {
+    fn check_length(&self, len: usize) -> PyResult<()> {
+        if let Some(min_length) = self.min_length.filter(|&min_length| len < min_length) {
+            return Err(ConstraintError::new_err(format!(
+                "String has {} characters, expected at least {}",
+                len, min_length
+            )));
+        }
+        if let Some(max_length) = self.max_length.filter(|&max_length| len > max_length) {
+            return Err(ConstraintError::new_err(format!(
+                "String has {} characters, expected at most {}",
+                len, max_length
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Encoder for StringEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        self.encoder.dump(value)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        let mut data = py_str_to_str(value)?;
+        if self.strip_whitespace {
+            data = data.trim();
+        }
+        let data = match (self.to_lower, self.to_upper) {
+            (true, _) => Cow::Owned(data.to_lowercase()),
+            (_, true) => Cow::Owned(data.to_uppercase()),
+            _ => Cow::Borrowed(data),
+        };
+        if !opts.trusted {
+            self.check_length(data.chars().count())?;
+        }
+        let transformed = py_str_from_str(&data)?;
+        let result = self.encoder.load(transformed, opts);
+        // the transformed string is returned as is by passthrough encoders and owns the reference then
+        if !matches!(result, Ok(loaded) if loaded == transformed) {
+            ffi!(Py_DECREF(transformed));
+        }
+        result
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        self.encoder.traverse(visit)
+    }
+}
+
+/// SecretBytes values are revealed and loaded as base64, like `bytes` fields with `BytesEncoding.base64`.
+const BASE64: BytesEncoding = BytesEncoding::Base64 {
+    url_safe: false,
+    padded: true,
+};
+
+#[derive(Debug, Clone)]
+pub struct SecretEncoder {
+    pub(crate) cls: pyo3::PyObject,
+    pub(crate) redact: bool,
+    pub(crate) placeholder: String,
+    pub(crate) bytes: bool,
+    pub(crate) jwt: bool,
+}
+
+impl Encoder for SecretEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        if self.redact {
+            return py_str_from_str(&self.placeholder);
+        }
+        let secret = call_method!(value, GET_SECRET_VALUE_STR)?;
+        if !self.bytes {
+            return Ok(secret);
+        }
+        let result = py_bytes_to_slice(secret)
+            .and_then(|data| py_str_with_buffer(|buffer| BASE64.encode(data, buffer)));
+        ffi!(Py_DECREF(secret));
+        result
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        // errors never include the value
+        if self.bytes && ffi!(PyBytes_Check(value)) != 0 {
+            return py_object_call1_make_tuple_or_err(self.cls.as_ptr(), value);
+        }
+        if ffi!(PyUnicode_Check(value)) == 0 {
+            return Err(TypeMismatchError::new_err(match self.bytes {
+                true => "Secret must be a string or bytes",
+                false => "Secret must be a string",
+            }));
+        }
+        let data = py_str_to_str(value)?;
+        if self.jwt && !is_jwt(data) {
+            return Err(ValidationError::new_err("Secret is not a valid JWT"));
+        }
+        if self.bytes {
+            let decoded = py_bytes_from_slice(&BASE64.decode(data)?)?;
+            let result = py_object_call1_make_tuple_or_err(self.cls.as_ptr(), decoded);
+            ffi!(Py_DECREF(decoded));
+            return result;
+        }
+        py_object_call1_make_tuple_or_err(self.cls.as_ptr(), value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SemVerEncoder {
+    pub(crate) min: Option<Version>,
+    pub(crate) max: Option<Version>,
+}
+
+impl Encoder for SemVerEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        Ok(value)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        if opts.trusted {
+            return Ok(value);
+        }
+        let data = py_str_to_str(value)?;
+        let version = Version::parse(data).ok_or_else(|| {
+            ValidationError::new_err(format!("{:?} is not a valid semantic version", data))
+        })?;
+        let bounds = [
+            (&self.min, Ordering::Less, "less than the minimum"),
+            (&self.max, Ordering::Greater, "greater than the maximum"),
+        ];
+        for (bound, ordering, message) in bounds {
+            if let Some(bound) = bound {
+                if version.cmp(bound) == ordering {
+                    return Err(ConstraintError::new_err(format!(
+                        "{} is {} of {}",
+                        data, message, bound
+                    )));
+                }
+            }
+        }
+        Ok(value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CodeEncoder {
+    pub(crate) format: CodeFormat,
+}
 
-impl Encoder for NoopEncoder {
+impl Encoder for CodeEncoder {
     #[inline]
     fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
         Ok(value)
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        self.format.validate(py_str_to_str(value)?)?;
         Ok(value)
     }
 }
 
+#[cfg(feature = "phone")]
 #[derive(Debug, Clone)]
-pub struct DecimalEncoder;
+pub struct PhoneEncoder;
 
-impl Encoder for DecimalEncoder {
+#[cfg(feature = "phone")]
+impl Encoder for PhoneEncoder {
     #[inline]
     fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        obj_to_str(value)
+        Ok(value)
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        to_decimal(value).map_err(|e| {
-            ValidationError::new_err(format!("invalid Decimal value: {:?} error: {:?}", value, e))
-        })
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        py_str_from_str(&normalize_phone(py_str_to_str(value)?)?)
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct DictionaryEncoder {
-    pub key_encoder: Box<TEncoder>,
-    pub value_encoder: Box<TEncoder>,
+pub struct PathEncoder {
+    pub(crate) cls: pyo3::PyObject,
+    pub(crate) absolute: bool,
+    pub(crate) allow_parent_refs: bool,
 }
 
-impl Encoder for DictionaryEncoder {
-    #[inline]
-    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        let dict_ptr = ffi!(PyDict_New());
-
-        for i in iter_over_dict_items(value)? {
-            let item = i?;
-            let key = self.key_encoder.dump(py_tuple_get_item(item, 0)?)?;
-            let value = self.value_encoder.dump(py_tuple_get_item(item, 1)?)?;
-
-            ffi!(PyDict_SetItem(dict_ptr, key, value));
+impl PathEncoder {
+    fn check(&self, path: *mut PyObject) -> PyResult<()> {
+        if self.absolute {
+            let is_absolute = call_method!(path, IS_ABSOLUTE_STR)?;
+            let result = py_object_is_true(is_absolute);
+            ffi!(Py_DECREF(is_absolute));
+            if !result? {
+                return Err(ConstraintError::new_err(format!(
+                    "Path {:?} is not absolute",
+                    py_object_to_string(path)
+                )));
+            }
+        }
+        if !self.allow_parent_refs {
+            let parts = py_object_get_attr(path, unsafe { PARTS_STR })?;
+            let result = self.check_parts(path, parts);
+            ffi!(Py_DECREF(parts));
+            result?;
         }
+        Ok(())
+    }
 
-        Ok(dict_ptr)
+    fn check_parts(&self, path: *mut PyObject, parts: *mut PyObject) -> PyResult<()> {
+        for part in to_iter(parts)? {
+            let part = part?;
+            let is_parent = py_str_to_str(part).map(|part| part == "..");
+            ffi!(Py_DECREF(part));
+            if is_parent? {
+                return Err(ConstraintError::new_err(format!(
+                    "Path {:?} must not contain '..'",
+                    py_object_to_string(path)
+                )));
+            }
+        }
+        Ok(())
     }
+}
 
+impl Encoder for PathEncoder {
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        let dict_ptr = ffi!(PyDict_New());
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        obj_to_str(value)
+    }
 
-        for i in iter_over_dict_items(value)? {
-            let item = i?;
-            let key = self.key_encoder.load(py_tuple_get_item(item, 0)?)?;
-            let value = self.value_encoder.load(py_tuple_get_item(item, 1)?)?;
-            ffi!(PyDict_SetItem(dict_ptr, key, value));
+    #[inline]
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        py_str_to_str(value)?;
+        let path = py_object_call1_make_tuple_or_err(self.cls.as_ptr(), value)?;
+        if let Err(e) = self.check(path) {
+            ffi!(Py_DECREF(path));
+            return Err(e);
         }
-
-        Ok(dict_ptr)
+        Ok(path)
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct ArrayEncoder {
-    pub encoder: Box<TEncoder>,
+pub struct EnumEncoder {
+    pub(crate) enum_type: pyo3::PyObject,
+    pub(crate) name: String,
+    // member names and str values
+    pub(crate) members: Vec<(String, String)>,
+    pub(crate) load_names: bool,
+    pub(crate) load_values: bool,
+    pub(crate) dump_format: EnumDumpFormat,
 }
 
-impl Encoder for ArrayEncoder {
-    #[inline]
-    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        let len = py_len(value)?;
-
-        let list = ffi!(PyList_New(len));
+#[derive(Debug, Clone, Copy)]
+pub enum EnumDumpFormat {
+    Str,
+    Value,
+    Name,
+}
 
-        for i in 0..len {
-            let item = ffi!(PyList_GetItem(value, i));
-            let val = self.encoder.dump(item)?;
+impl EnumDumpFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "str" => Some(Self::Str),
+            "value" => Some(Self::Value),
+            "name" => Some(Self::Name),
+            _ => None,
+        }
+    }
+}
 
-            ffi!(PyList_SetItem(list, i, val));
+impl EnumEncoder {
+    fn not_a_member(&self, value: *mut PyObject) -> PyErr {
+        let value = py_object_to_string(value);
+        let mut message = format!("{:?} is not a member of {}", value, self.name);
+        // member names and values both point at the member name
+        let candidates = self
+            .members
+            .iter()
+            .flat_map(|(name, value)| [(name.as_str(), name), (value.as_str(), name)]);
+        if let Some(name) = closest_match(&value, candidates) {
+            message.push_str(&format!(", did you mean {}.{}?", self.name, name));
         }
+        TypeMismatchError::new_err(message)
+    }
+}
 
-        Ok(list)
+impl Encoder for EnumEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let attr = match self.dump_format {
+            EnumDumpFormat::Name => unsafe { NAME_STR },
+            EnumDumpFormat::Str | EnumDumpFormat::Value => unsafe { VALUE_STR },
+        };
+        let member_value = py_object_get_attr(value, attr).map_err(|_| self.not_a_member(value))?;
+        match self.dump_format {
+            EnumDumpFormat::Str => {
+                let result = obj_to_str(member_value);
+                ffi!(Py_DECREF(member_value));
+                result
+            }
+            EnumDumpFormat::Value | EnumDumpFormat::Name => Ok(member_value),
+        }
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        let len = py_len(value)?;
-        let list = ffi!(PyList_New(len));
-        for i in 0..len {
-            let item = ffi!(PyList_GetItem(value, i));
-            let val = self.encoder.load(item)?;
-            ffi!(PyList_SetItem(list, i, val));
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        if self.load_names {
+            // Enum classes look up members by name with []
+            if ffi!(PyUnicode_Check(value)) != 0 {
+                if let Ok(member) = py_object_get_item(self.enum_type.as_ptr(), value) {
+                    return Ok(member);
+                }
+            }
+            if !self.load_values {
+                return Err(self.not_a_member(value));
+            }
         }
-        Ok(list)
+        py_object_call1_make_tuple_or_err(self.enum_type.as_ptr(), value)
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        visit.call(&self.enum_type)
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct EntityEncoder {
-    pub(crate) create_new_object_args: Py<PyTuple>,
-    pub(crate) fields: Vec<Field>,
+pub struct FlagEncoder {
+    pub(crate) flag_type: pyo3::PyObject,
+    pub(crate) name: String,
+    // single bit members in definition order, combined members are dumped by these
+    pub(crate) members: Vec<(String, u64)>,
+    pub(crate) all_bits: u64,
+    pub(crate) dump_names: bool,
 }
 
-#[derive(Debug, Clone)]
-pub struct Field {
-    pub(crate) name: Py<PyString>,
-    pub(crate) dict_key: Py<PyString>,
-    pub(crate) encoder: Box<TEncoder>,
-    pub(crate) default: Option<Py<PyAny>>,
-    pub(crate) default_factory: Option<Py<PyAny>>,
+impl FlagEncoder {
+    fn member_bits(&self, name: &PyAny) -> PyResult<u64> {
+        match self.flag_type.as_ref(name.py()).get_item(name) {
+            Ok(member) => member.getattr("value")?.extract(),
+            Err(_) => {
+                let name = name.to_string();
+                let mut message = format!("{:?} is not a member of {}", name, self.name);
+                let candidates = self.members.iter().map(|(name, _)| (name.as_str(), name));
+                if let Some(found) = closest_match(&name, candidates) {
+                    message.push_str(&format!(", did you mean {}.{}?", self.name, found));
+                }
+                Err(TypeMismatchError::new_err(message))
+            }
+        }
+    }
 }
 
-impl Encoder for EntityEncoder {
+impl Encoder for FlagEncoder {
     #[inline]
     fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        let dict_ptr = ffi!(PyDict_New());
-
-        for field in &self.fields {
-            let field_val = ffi!(PyObject_GetAttr(value, field.name.as_ptr()));
-            let dump_result = field.encoder.dump(field_val)?;
-            ffi!(PyDict_SetItem(
-                dict_ptr,
-                field.dict_key.as_ptr(),
-                dump_result
-            ));
-        }
-
-        Ok(dict_ptr)
+        Python::with_gil(|py| {
+            let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+            let bits: u64 = value.getattr("value")?.extract()?;
+            if !self.dump_names {
+                return Ok(bits.into_py(py).into_ptr());
+            }
+            let names = self
+                .members
+                .iter()
+                .filter(|(_, member_bits)| bits & member_bits != 0)
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>();
+            Ok(PyList::new(py, names).into_ptr())
+        })
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
         Python::with_gil(|py| {
-            let obj = create_new_object(self.create_new_object_args.as_ref(py))?;
-            for field in &self.fields {
-                let val = match py_object_get_item(value, field.dict_key.as_ptr()) {
-                    Ok(val) => field.encoder.load(val)?,
-                    Err(e) => match (&field.default, &field.default_factory) {
-                        (Some(val), _) => val.clone().as_ptr(),
-                        (_, Some(val)) => call_object!(val.as_ptr())?,
-                        (None, _) => {
-                            return Err(ValidationError::new_err(format!(
-                                "data dictionary is missing required parameter {} (err: {})",
-                                &field.name, e
-                            )))
-                        }
-                    },
-                };
-                py_object_set_attr(obj, field.name.as_ptr(), val)?
-            }
-            Ok(obj)
+            let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+            let bits = match value.downcast::<PyList>() {
+                Ok(names) => names
+                    .iter()
+                    .map(|name| self.member_bits(name))
+                    .try_fold(0, |bits, member_bits| member_bits.map(|b| bits | b))?,
+                Err(_) => match value.extract::<u64>() {
+                    Ok(bits) if bits & !self.all_bits == 0 => bits,
+                    _ => {
+                        return Err(TypeMismatchError::new_err(format!(
+                            "{} is not a valid {}",
+                            value, self.name
+                        )))
+                    }
+                },
+            };
+            Ok(self.flag_type.call1(py, (bits,))?.into_ptr())
         })
     }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        visit.call(&self.flag_type)
+    }
 }
 
 #[derive(Debug, Clone)]
-pub struct UUIDEncoder;
+pub struct CustomEncoder {
+    pub(crate) encoder: pyo3::PyObject,
+}
 
-impl Encoder for UUIDEncoder {
+impl Encoder for CustomEncoder {
     #[inline]
     fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        obj_to_str(value)
+        call_method!(self.encoder.as_ptr(), DUMP_STR, value)
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        py_object_call1_make_tuple_or_err(unsafe { UUID_PY_TYPE }, value)
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        call_method!(self.encoder.as_ptr(), LOAD_STR, value)
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        visit.call(&self.encoder)
     }
 }
 
+/// Delegates to `model_dump(mode="json")` and `model_validate` of a pydantic model.
 #[derive(Debug, Clone)]
-pub struct EnumEncoder {
-    pub(crate) enum_type: pyo3::PyObject,
+pub struct PydanticModelEncoder {
+    pub(crate) model: pyo3::PyObject,
 }
 
-impl Encoder for EnumEncoder {
+impl Encoder for PydanticModelEncoder {
     #[inline]
     fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        obj_to_str(py_object_get_attr(value, unsafe { VALUE_STR })?)
+        Python::with_gil(|py| {
+            let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+            let kwargs = [("mode", "json")].into_py_dict(py);
+            Ok(value
+                .call_method("model_dump", (), Some(kwargs))?
+                .into_ptr())
+        })
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        py_object_call1_make_tuple_or_err(self.enum_type.as_ptr(), value)
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        Python::with_gil(|py| {
+            let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+            self.model
+                .as_ref(py)
+                .call_method1("model_validate", (value,))
+                .map(|model| model.into_ptr())
+                .map_err(|e| value_error_to_validation_error(py, e))
+        })
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        visit.call(&self.model)
     }
 }
 
@@ -265,12 +1699,56 @@ impl Encoder for OptionalEncoder {
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
         if value == unsafe { NONE_PY_TYPE } {
             Ok(value)
         } else {
-            self.encoder.load(value)
+            self.encoder.load(value, opts)
+        }
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        self.encoder.traverse(visit)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonStringEncoder {
+    pub(crate) encoder: Box<TEncoder>,
+}
+
+impl Encoder for JsonStringEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let data = self.encoder.dump(value)?;
+        let result = py_object_call1_make_tuple_or_err(unsafe { JSON_DUMPS }, data);
+        // passthrough encoders return the value itself, it's borrowed
+        if data != value {
+            ffi!(Py_DECREF(data));
+        }
+        result
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        if ffi!(PyUnicode_Check(value)) == 0 {
+            return Err(TypeMismatchError::new_err(format!(
+                "{:?} is not a json string",
+                py_object_to_string(value)
+            )));
+        }
+        let data = py_object_call1_make_tuple_or_err(unsafe { JSON_LOADS }, value)
+            .map_err(|e| ValidationError::new_err(format!("Fail parse json string: {}", e)))?;
+        let result = self.encoder.load(data, opts);
+        // the parsed data is returned as is by passthrough encoders and owns the reference then
+        if !matches!(result, Ok(loaded) if loaded == data) {
+            ffi!(Py_DECREF(data));
         }
+        result
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        self.encoder.traverse(visit)
     }
 }
 
@@ -284,24 +1762,26 @@ impl Encoder for TupleEncoder {
     fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
         let len = py_len(value)?;
         if len != self.encoders.len() as isize {
-            return Err(ValidationError::new_err(
+            return Err(ConstraintError::new_err(
                 "Invalid number of items for tuple",
             ));
         }
         let list = ffi!(PyList_New(len));
         for i in 0..len {
             let item = ffi!(PySequence_GetItem(value, i));
-            let val = self.encoders[i as usize].dump(item)?;
+            let val = self.encoders[i as usize]
+                .dump(item)
+                .map_err(|e| with_dump_path(e, || i.to_string()))?;
             ffi!(PyList_SetItem(list, i, val));
         }
         Ok(list)
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
         let len = py_len(value)?;
         if len != self.encoders.len() as isize {
-            return Err(ValidationError::new_err(
+            return Err(ConstraintError::new_err(
                 "Invalid number of items for tuple",
             ));
         }
@@ -309,45 +1789,310 @@ impl Encoder for TupleEncoder {
         let list = ffi!(PyTuple_New(len));
         for i in 0..len {
             let item = ffi!(PyList_GetItem(value, i));
-            let val = self.encoders[i as usize].load(item)?;
-            ffi!(PyTuple_SetItem(list, i, val));
+            let val = self.encoders[i as usize]
+                .load(item, opts)
+                .map_err(|e| opts.with_path(e, || PathKey::Index(i)))?;
+            ffi!(PyTuple_SetItem(list, i, own_item(item, val)));
         }
         Ok(list)
     }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        for encoder in &self.encoders {
+            encoder.traverse(visit)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VariadicTupleEncoder {
+    pub(crate) encoder: Box<TEncoder>,
+    pub(crate) min_length: Option<usize>,
+    pub(crate) max_length: Option<usize>,
+}
+
+impl VariadicTupleEncoder {
+    fn check_length(&self, len: usize) -> PyResult<()> {
+        if let Some(min_length) = self.min_length.filter(|&min_length| len < min_length) {
+            return Err(ConstraintError::new_err(format!(
+                "Tuple has {} items, expected at least {}",
+                len, min_length
+            )));
+        }
+        if let Some(max_length) = self.max_length.filter(|&max_length| len > max_length) {
+            return Err(ConstraintError::new_err(format!(
+                "Tuple has {} items, expected at most {}",
+                len, max_length
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Encoder for VariadicTupleEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let len = py_len(value)?;
+        let list = ffi!(PyList_New(len));
+        for i in 0..len {
+            let result = from_ptr_or_err(ffi!(PySequence_GetItem(value, i))).and_then(|item| {
+                let val = self.encoder.dump(item);
+                match val {
+                    Ok(val) => SetEncoder::release_item(item, val),
+                    Err(_) => ffi!(Py_DECREF(item)),
+                }
+                val
+            });
+            match result {
+                Ok(val) => ffi!(PyList_SetItem(list, i, val)),
+                Err(e) => {
+                    ffi!(Py_DECREF(list));
+                    return Err(with_dump_path(e, || i.to_string()));
+                }
+            };
+        }
+        Ok(list)
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        let len = py_len(value)?;
+        if !opts.trusted {
+            self.check_length(len as usize)?;
+        }
+        let tuple = ffi!(PyTuple_New(len));
+        for i in 0..len {
+            let result = from_ptr_or_err(ffi!(PySequence_GetItem(value, i))).and_then(|item| {
+                let val = opts
+                    .check_limits()
+                    .and_then(|_| self.encoder.load(item, opts));
+                match val {
+                    Ok(val) => SetEncoder::release_item(item, val),
+                    Err(_) => ffi!(Py_DECREF(item)),
+                }
+                val
+            });
+            match result {
+                Ok(val) => ffi!(PyTuple_SetItem(tuple, i, val)),
+                Err(e) => {
+                    ffi!(Py_DECREF(tuple));
+                    return Err(opts.with_path(e, || PathKey::Index(i)));
+                }
+            };
+        }
+        Ok(tuple)
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        self.encoder.traverse(visit)
+    }
 }
 
 #[derive(Debug, Clone)]
-pub struct TimeEncoder;
+pub struct TimeEncoder {
+    pub(crate) precision: Option<SubsecondPrecision>,
+    pub(crate) min: Option<Py<PyAny>>,
+    pub(crate) max: Option<Py<PyAny>>,
+}
 
 impl Encoder for TimeEncoder {
     #[inline]
     fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        call_method!(value, ISOFORMAT_STR)
+        match &self.precision {
+            Some(precision) => {
+                let value = precision.apply(value)?;
+                let result = precision.isoformat(value);
+                ffi!(Py_DECREF(value));
+                result
+            }
+            None => call_method!(value, ISOFORMAT_STR),
+        }
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        parse_time(py_str_to_str(value)?)
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        check_range(
+            parse_time(py_str_to_str(value)?)?,
+            &self.min,
+            &self.max,
+            opts,
+        )
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct DateTimeEncoder;
+pub struct DateTimeEncoder {
+    pub(crate) formats: Vec<DateTimeFormat>,
+    pub(crate) dump_format: Option<DateTimeFormat>,
+    pub(crate) z_suffix: bool,
+    pub(crate) precision: Option<SubsecondPrecision>,
+    pub(crate) require_aware: bool,
+    pub(crate) assume_timezone: Option<Py<PyAny>>,
+    pub(crate) convert_to_utc: bool,
+    pub(crate) min: Option<Py<PyAny>>,
+    pub(crate) max: Option<Py<PyAny>>,
+    pub(crate) clamp_year: bool,
+}
+
+impl DateTimeEncoder {
+    fn load_with_format(
+        &self,
+        value: *mut PyObject,
+        format: &DateTimeFormat,
+    ) -> PyResult<*mut PyObject> {
+        match format {
+            DateTimeFormat::Iso | DateTimeFormat::IsoSeconds => {
+                parse_datetime(py_str_to_str(value)?, self.clamp_year)
+            }
+            DateTimeFormat::Epoch => {
+                datetime_from_timestamp(py_float_as_f64(value)?, self.clamp_year)
+            }
+            DateTimeFormat::EpochMillis => {
+                datetime_from_timestamp(py_float_as_f64(value)? / 1000.0, self.clamp_year)
+            }
+            DateTimeFormat::Rfc2822 => {
+                parse_rfc2822_datetime(py_str_to_str(value)?, self.clamp_year)
+            }
+            DateTimeFormat::Custom(format) => {
+                parse_datetime_with_format(py_str_to_str(value)?, format, self.clamp_year)
+            }
+        }
+    }
+
+    fn apply_timezone_policy(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        if !self.require_aware && self.assume_timezone.is_none() && !self.convert_to_utc {
+            return Ok(value);
+        }
+        Python::with_gil(|py| {
+            let mut datetime = unsafe { py.from_owned_ptr::<PyAny>(value) };
+            if datetime.call_method0("utcoffset")?.is_none() {
+                if let Some(tz) = &self.assume_timezone {
+                    let kwargs = [("tzinfo", tz)].into_py_dict(py);
+                    datetime = datetime.call_method("replace", (), Some(kwargs))?;
+                } else if self.require_aware {
+                    return Err(ConstraintError::new_err(format!(
+                        "Naive datetime {} is not allowed, timezone is required",
+                        datetime
+                    )));
+                } else {
+                    return Ok(datetime.into_ptr());
+                }
+            }
+            if self.convert_to_utc {
+                let utc = py.import("datetime")?.getattr("timezone")?.getattr("utc")?;
+                datetime = datetime.call_method1("astimezone", (utc,))?;
+            }
+            Ok(datetime.into_ptr())
+        })
+    }
+
+    fn dump_value(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        match self.dump_format.as_ref().or_else(|| self.formats.first()) {
+            None | Some(DateTimeFormat::Iso) => self.with_z_suffix(match &self.precision {
+                Some(precision) => precision.isoformat(value)?,
+                None => call_method!(value, ISOFORMAT_STR)?,
+            }),
+            Some(DateTimeFormat::IsoSeconds) => {
+                let result = Python::with_gil(|py| {
+                    let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+                    value
+                        .call_method1("isoformat", ("T", "seconds"))
+                        .map(|result| result.into_ptr())
+                })?;
+                self.with_z_suffix(result)
+            }
+            Some(DateTimeFormat::Epoch) => {
+                let utc_offset = call_method!(value, UTCOFFSET_STR)?;
+                let timestamp = datetime_to_timestamp(value, utc_offset);
+                ffi!(Py_DECREF(utc_offset));
+                py_float_from_f64(timestamp?)
+            }
+            Some(DateTimeFormat::EpochMillis) => {
+                let utc_offset = call_method!(value, UTCOFFSET_STR)?;
+                let timestamp = datetime_to_timestamp(value, utc_offset);
+                ffi!(Py_DECREF(utc_offset));
+                py_int_from_i64((timestamp? * 1000.0).round() as i64)
+            }
+            Some(DateTimeFormat::Rfc2822) => Python::with_gil(|py| {
+                let value = unsafe { py.from_borrowed_ptr::<PyAny>(value) };
+                let utils = py.import("email.utils")?;
+                Ok(utils.call_method1("format_datetime", (value,))?.into_ptr())
+            }),
+            Some(DateTimeFormat::Custom(format)) => {
+                let format = py_str_from_str(format)?;
+                let result = call_method!(value, STRFTIME_STR, format);
+                ffi!(Py_DECREF(format));
+                result
+            }
+        }
+    }
+
+    fn with_z_suffix(&self, result: *mut PyObject) -> PyResult<*mut PyObject> {
+        if !self.z_suffix {
+            return Ok(result);
+        }
+        match py_str_to_str(result)?.strip_suffix("+00:00") {
+            Some(datetime) => {
+                let with_z = py_str_with_buffer(|buffer| {
+                    buffer.push_str(datetime);
+                    buffer.push('Z');
+                });
+                ffi!(Py_DECREF(result));
+                with_z
+            }
+            None => Ok(result),
+        }
+    }
+}
 
 impl Encoder for DateTimeEncoder {
     #[inline]
     fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        call_method!(value, ISOFORMAT_STR)
+        match &self.precision {
+            Some(precision) => {
+                let value = precision.apply(value)?;
+                let result = self.dump_value(value);
+                ffi!(Py_DECREF(value));
+                result
+            }
+            None => self.dump_value(value),
+        }
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        parse_datetime(py_str_to_str(value)?)
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        let result = match self.formats.as_slice() {
+            [] => parse_datetime(py_str_to_str(value)?, self.clamp_year),
+            [format] => self.load_with_format(value, format),
+            formats => formats
+                .iter()
+                .find_map(|format| self.load_with_format(value, format).ok())
+                .ok_or_else(|| {
+                    let names: Vec<&str> = formats.iter().map(|f| f.name()).collect();
+                    ValidationError::new_err(format!(
+                        "Fail parse datetime {:?} with formats {:?}",
+                        py_object_to_string(value),
+                        names
+                    ))
+                }),
+        };
+        check_range(
+            self.apply_timezone_policy(result?)?,
+            &self.min,
+            &self.max,
+            opts,
+        )
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct DateEncoder;
+pub struct DateEncoder {
+    pub(crate) lenient: bool,
+    pub(crate) min: Option<Py<PyAny>>,
+    pub(crate) max: Option<Py<PyAny>>,
+    pub(crate) clamp_year: bool,
+}
 
 impl Encoder for DateEncoder {
     #[inline]
@@ -356,8 +2101,37 @@ impl Encoder for DateEncoder {
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
-        parse_date(py_str_to_str(value)?)
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        check_range(
+            parse_date(py_str_to_str(value)?, self.lenient, self.clamp_year)?,
+            &self.min,
+            &self.max,
+            opts,
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimeDurationEncoder {
+    pub(crate) format: DurationFormat,
+}
+
+impl Encoder for TimeDurationEncoder {
+    #[inline]
+    fn dump(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+        let micros = duration_to_micros(value)?;
+        match self.format {
+            DurationFormat::Iso8601 => py_str_with_buffer(|buffer| format_duration(micros, buffer)),
+            DurationFormat::Seconds => py_float_from_f64(micros as f64 / 1_000_000.0),
+        }
+    }
+
+    #[inline]
+    fn load(&self, value: *mut PyObject, _opts: &LoadOptions) -> PyResult<*mut PyObject> {
+        match self.format {
+            DurationFormat::Iso8601 => parse_duration(py_str_to_str(value)?),
+            DurationFormat::Seconds => duration_from_seconds(py_float_as_f64(value)?),
+        }
     }
 }
 
@@ -378,12 +2152,258 @@ impl Encoder for LazyEncoder {
     }
 
     #[inline]
-    fn load(&self, value: *mut PyObject) -> PyResult<*mut PyObject> {
+    fn load(&self, value: *mut PyObject, opts: &LoadOptions) -> PyResult<*mut PyObject> {
         match self.inner.borrow().as_ref() {
-            Some(encoder) => encoder.load(value),
+            Some(encoder) => encoder.load(value, opts),
             None => Err(PyRuntimeError::new_err(
                 "[RUST] Invalid recursive encoder".to_string(),
             )),
         }
     }
 }
+
+/// Bounds are min, max, exclusive_min and exclusive_max.
+fn check_number_bounds(
+    value: *mut PyObject,
+    bounds: [&Option<Py<PyAny>>; 4],
+    opts: &LoadOptions,
+) -> PyResult<()> {
+    if opts.trusted {
+        return Ok(());
+    }
+    let [min, max, exclusive_min, exclusive_max] = bounds;
+    let bounds = [
+        (min, CompareOp::Lt, "less than the minimum"),
+        (max, CompareOp::Gt, "greater than the maximum"),
+        (
+            exclusive_min,
+            CompareOp::Le,
+            "less than or equal to the minimum",
+        ),
+        (
+            exclusive_max,
+            CompareOp::Ge,
+            "greater than or equal to the maximum",
+        ),
+    ];
+    for (bound, op, message) in bounds {
+        if let Some(bound) = bound {
+            if py_object_rich_compare(value, bound.as_ptr(), op)? {
+                return Err(ConstraintError::new_err(format!(
+                    "{} is {} of {}",
+                    py_object_to_string(value),
+                    message,
+                    bound
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_multiple_of(
+    value: *mut PyObject,
+    multiple_of: &Option<Py<PyAny>>,
+    opts: &LoadOptions,
+) -> PyResult<()> {
+    if opts.trusted {
+        return Ok(());
+    }
+    if let Some(multiple_of) = multiple_of {
+        let not_multiple = || {
+            ConstraintError::new_err(format!(
+                "{} is not a multiple of {}",
+                py_object_to_string(value),
+                multiple_of
+            ))
+        };
+        // Decimal raises InvalidOperation for non-finite values and too large quotients
+        let remainder =
+            py_number_remainder(value, multiple_of.as_ptr()).map_err(
+                |e| match Python::with_gil(|py| e.is_instance_of::<PyArithmeticError>(py)) {
+                    true => not_multiple(),
+                    false => e,
+                },
+            )?;
+        let is_multiple = !py_object_is_true(remainder)?;
+        ffi!(Py_DECREF(remainder));
+        if !is_multiple {
+            return Err(not_multiple());
+        }
+    }
+    Ok(())
+}
+
+fn check_range(
+    value: *mut PyObject,
+    min: &Option<Py<PyAny>>,
+    max: &Option<Py<PyAny>>,
+    opts: &LoadOptions,
+) -> PyResult<*mut PyObject> {
+    if opts.trusted {
+        return Ok(value);
+    }
+    let bounds = [
+        (min, CompareOp::Lt, "less than the minimum"),
+        (max, CompareOp::Gt, "greater than the maximum"),
+    ];
+    for (bound, op, message) in bounds {
+        if let Some(bound) = bound {
+            // naive and aware datetimes are not comparable
+            let out_of_range = py_object_rich_compare(value, bound.as_ptr(), op)
+                .map_err(|e| ValidationError::new_err(e.to_string()))?;
+            if out_of_range {
+                return Err(ConstraintError::new_err(format!(
+                    "{} is {} of {}",
+                    py_object_to_string(value),
+                    message,
+                    bound
+                )));
+            }
+        }
+    }
+    Ok(value)
+}
+
+/// Builds `serpyco_rs.exceptions.SchemaValidationError` with a single error item.
+fn schema_validation_error(message: String, instance_path: String, schema_path: &str) -> PyErr {
+    Python::with_gil(|py| {
+        let make_error = || -> PyResult<PyErr> {
+            let exceptions = py.import("serpyco_rs.exceptions")?;
+            let item = exceptions.getattr("ErrorItem")?.call1((
+                message.as_str(),
+                instance_path,
+                schema_path,
+            ))?;
+            let error = exceptions
+                .getattr("SchemaValidationError")?
+                .call1((vec![item],))?;
+            Ok(PyErr::from_value(error))
+        };
+        make_error().unwrap_or_else(|e| e)
+    })
+}
+
+fn invalid_uuid(value: *mut PyObject) -> PyErr {
+    ValidationError::new_err(format!(
+        "{:?} is not a valid UUID",
+        py_object_to_string(value)
+    ))
+}
+
+// header.payload.signature, each part is unpadded base64url
+fn is_jwt(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('.').collect();
+    parts.len() == 3
+        && parts.iter().all(|part| {
+            !part.is_empty()
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        })
+}
+
+/// Calls `check` with the items of the list or with their `field` attribute values.
+fn with_item_keys(
+    list: *mut PyObject,
+    len: isize,
+    field: Option<&Py<PyString>>,
+    check: impl FnOnce(&[*mut PyObject]) -> PyResult<()>,
+) -> PyResult<()> {
+    let mut keys = Vec::with_capacity(len as usize);
+    let mut result = Ok(());
+    for i in 0..len {
+        let item = ffi!(PyList_GetItem(list, i));
+        match field {
+            Some(field) => match py_object_get_attr(item, field.as_ptr()) {
+                Ok(key) => keys.push(key),
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            },
+            None => keys.push(item),
+        }
+    }
+    let result = result.and_then(|_| check(&keys));
+    if field.is_some() {
+        keys.iter().for_each(|&key| ffi!(Py_DECREF(key)));
+    }
+    result
+}
+
+fn find_duplicate(keys: &[*mut PyObject], field: Option<&Py<PyString>>) -> PyResult<()> {
+    let mut seen: HashMap<Py_hash_t, Vec<(usize, *mut PyObject)>> = HashMap::new();
+    let mut unhashable: Vec<(usize, *mut PyObject)> = vec![];
+    for (i, &key) in keys.iter().enumerate() {
+        let candidates = match py_object_hash(key) {
+            Ok(hash) => seen.entry(hash).or_default(),
+            Err(_) => &mut unhashable,
+        };
+        for &(j, other) in candidates.iter() {
+            if py_object_rich_compare(key, other, CompareOp::Eq)? {
+                let (message, schema_path) = match field {
+                    Some(field) => (
+                        format!("Item {} has the same {} as item {}", i, field, j),
+                        "uniqueBy",
+                    ),
+                    None => (
+                        format!("Item {} is a duplicate of item {}", i, j),
+                        "uniqueItems",
+                    ),
+                };
+                return Err(schema_validation_error(message, i.to_string(), schema_path));
+            }
+        }
+        candidates.push((i, key));
+    }
+    Ok(())
+}
+
+fn check_sorted(keys: &[*mut PyObject], field: &Py<PyString>, descending: bool) -> PyResult<()> {
+    let (op, order) = match descending {
+        false => (CompareOp::Lt, "ascending"),
+        true => (CompareOp::Gt, "descending"),
+    };
+    for (i, pair) in keys.windows(2).enumerate() {
+        if py_object_rich_compare(pair[1], pair[0], op)? {
+            return Err(schema_validation_error(
+                format!("Item {} breaks the {} order by {}", i + 1, order, field),
+                (i + 1).to_string(),
+                "sortedBy",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// `ValueError` from user code (like `__post_init__` checks) is reported as a validation error.
+fn value_error_to_validation_error(py: Python, e: PyErr) -> PyErr {
+    if !e.is_instance_of::<PyValueError>(py) {
+        return e;
+    }
+    let error = ValidationError::new_err(e.value(py).to_string());
+    error.set_cause(py, Some(e));
+    error
+}
+
+fn key_tree(name: &str, paths: Option<&PyAny>) -> PyResult<Option<Rc<KeyTree>>> {
+    let paths = match paths {
+        // a str is an iterable of its characters, not a single path
+        Some(paths)
+            if paths.is_instance_of::<PyString>()? || paths.is_instance_of::<PyBytes>()? =>
+        {
+            return Err(PyTypeError::new_err(format!(
+                "{} must be an iterable of paths, not {}",
+                name,
+                paths.get_type().name()?
+            )))
+        }
+        Some(paths) => paths
+            .iter()?
+            .map(|path| path?.extract())
+            .collect::<PyResult<Vec<&str>>>()?,
+        None => return Ok(None),
+    };
+    Ok(Some(Rc::new(KeyTree::from_paths(paths))))
+}
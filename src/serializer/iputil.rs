@@ -0,0 +1,109 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use pyo3::{PyErr, PyResult};
+
+use super::encoders::ValidationError;
+
+#[derive(Debug, Clone, Copy)]
+pub enum IpKind {
+    Address,
+    Network,
+    Interface,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum IpVersion {
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    pub fn from_number(version: u8) -> Option<Self> {
+        match version {
+            4 => Some(Self::V4),
+            6 => Some(Self::V6),
+            _ => None,
+        }
+    }
+
+    fn max_prefix(&self) -> u32 {
+        match self {
+            Self::V4 => 32,
+            Self::V6 => 128,
+        }
+    }
+}
+
+/// Checks the value the same way `ipaddress` constructors do,
+/// networks must not have host bits set.
+pub fn validate_ip(value: &str, version: IpVersion, kind: IpKind) -> PyResult<()> {
+    let valid = match kind {
+        IpKind::Address => parse_address(value, version).is_some(),
+        IpKind::Network => parse_prefixed(value, version)
+            .is_some_and(|(address, prefix)| host_bits(address, prefix, version) == 0),
+        IpKind::Interface => parse_prefixed(value, version).is_some(),
+    };
+    match valid {
+        true => Ok(()),
+        false => Err(invalid_ip(value, version, kind)),
+    }
+}
+
+fn parse_address(value: &str, version: IpVersion) -> Option<u128> {
+    match version {
+        IpVersion::V4 => value.parse::<Ipv4Addr>().ok().map(|a| u32::from(a) as u128),
+        IpVersion::V6 => value.parse::<Ipv6Addr>().ok().map(u128::from),
+    }
+}
+
+fn parse_prefixed(value: &str, version: IpVersion) -> Option<(u128, u32)> {
+    let (address, prefix) = match value.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (value, None),
+    };
+    let address = parse_address(address, version)?;
+    let prefix = match prefix {
+        None => version.max_prefix(),
+        Some(prefix) if prefix.bytes().all(|b| b.is_ascii_digit()) && !prefix.is_empty() => {
+            prefix.parse().ok().filter(|&p| p <= version.max_prefix())?
+        }
+        // IPv4 also accepts netmasks and hostmasks: 10.0.0.0/255.0.0.0 or 10.0.0.0/0.255.255.255
+        Some(mask) => match version {
+            IpVersion::V4 => mask_to_prefix(mask.parse::<Ipv4Addr>().ok()?.into())?,
+            IpVersion::V6 => return None,
+        },
+    };
+    Some((address, prefix))
+}
+
+fn mask_to_prefix(mask: u32) -> Option<u32> {
+    if mask.leading_ones() + mask.trailing_zeros() == 32 {
+        Some(mask.leading_ones())
+    } else if mask.leading_zeros() + mask.trailing_ones() == 32 {
+        Some(mask.leading_zeros())
+    } else {
+        None
+    }
+}
+
+fn host_bits(address: u128, prefix: u32, version: IpVersion) -> u128 {
+    let host_len = version.max_prefix() - prefix;
+    match host_len {
+        0 => 0,
+        128 => address,
+        _ => address & ((1u128 << host_len) - 1),
+    }
+}
+
+fn invalid_ip(value: &str, version: IpVersion, kind: IpKind) -> PyErr {
+    let version = match version {
+        IpVersion::V4 => "IPv4",
+        IpVersion::V6 => "IPv6",
+    };
+    let kind = match kind {
+        IpKind::Address => "address",
+        IpKind::Network => "network",
+        IpKind::Interface => "interface",
+    };
+    ValidationError::new_err(format!("{:?} is not a valid {} {}", value, version, kind))
+}
@@ -0,0 +1,124 @@
+use pyo3::{PyErr, PyResult};
+
+use super::currency::validate_currency;
+use super::encoders::ValidationError;
+
+/// ISO 3166-1 alpha-2 codes, sorted for binary search.
+static COUNTRY_CODES: [&str; 249] = [
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+#[derive(Debug, Clone, Copy)]
+pub enum CodeFormat {
+    Country,
+    LanguageTag,
+    Currency,
+}
+
+impl CodeFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "iso3166-alpha2" => Some(Self::Country),
+            "bcp47" => Some(Self::LanguageTag),
+            "iso4217" => Some(Self::Currency),
+            _ => None,
+        }
+    }
+
+    pub fn validate(&self, value: &str) -> PyResult<()> {
+        match self {
+            Self::Country if is_country_code(value) => Ok(()),
+            Self::Country => Err(invalid_code(value, "ISO 3166 alpha-2 country code")),
+            Self::LanguageTag if is_language_tag(value) => Ok(()),
+            Self::LanguageTag => Err(invalid_code(value, "BCP 47 language tag")),
+            Self::Currency => validate_currency(value),
+        }
+    }
+}
+
+fn is_country_code(value: &str) -> bool {
+    COUNTRY_CODES.binary_search(&value).is_ok()
+}
+
+/// Checks the RFC 5646 tag structure, regions are checked against ISO 3166.
+/// Subtags are case-insensitive.
+fn is_language_tag(value: &str) -> bool {
+    let subtags: Vec<&str> = value.split('-').collect();
+    let at = |i: usize| subtags.get(i).copied().unwrap_or("");
+    let mut i = 0;
+    // language: 2-3 letters with up to three extlangs, or 4-8 letters
+    if is_alpha(at(i), 2, 3) {
+        i += 1;
+        let start = i;
+        while i - start < 3 && is_alpha(at(i), 3, 3) {
+            i += 1;
+        }
+    } else if is_alpha(at(i), 4, 8) {
+        i += 1;
+    } else {
+        return is_private_use(&subtags);
+    }
+    // script
+    if is_alpha(at(i), 4, 4) {
+        i += 1;
+    }
+    // region
+    if (is_alpha(at(i), 2, 2) && is_country_code(&at(i).to_ascii_uppercase()))
+        || (at(i).len() == 3 && at(i).bytes().all(|b| b.is_ascii_digit()))
+    {
+        i += 1;
+    }
+    // variants
+    while is_alnum(at(i), 5, 8) || (is_alnum(at(i), 4, 4) && at(i).as_bytes()[0].is_ascii_digit()) {
+        i += 1;
+    }
+    // extensions: a singleton followed by one or more 2-8 character subtags
+    while is_alnum(at(i), 1, 1) && !at(i).eq_ignore_ascii_case("x") {
+        i += 1;
+        if !is_alnum(at(i), 2, 8) {
+            return false;
+        }
+        while is_alnum(at(i), 2, 8) {
+            i += 1;
+        }
+    }
+    i == subtags.len() || is_private_use(&subtags[i..])
+}
+
+fn is_private_use(subtags: &[&str]) -> bool {
+    match subtags {
+        [x, rest @ ..] => {
+            x.eq_ignore_ascii_case("x")
+                && !rest.is_empty()
+                && rest.iter().all(|s| is_alnum(s, 1, 8))
+        }
+        [] => false,
+    }
+}
+
+fn is_alpha(value: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&value.len()) && value.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_alnum(value: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&value.len()) && value.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn invalid_code(value: &str, kind: &str) -> PyErr {
+    ValidationError::new_err(format!("{:?} is not a valid {}", value, kind))
+}
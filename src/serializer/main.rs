@@ -1,10 +1,24 @@
+use crate::serializer::bytesutil::BytesEncoding;
+use crate::serializer::dateutil::{
+    duration_to_micros, format_duration, parse_duration, DateTimeFormat, DurationFormat,
+    SubsecondPrecision,
+};
+#[cfg(feature = "phone")]
+use crate::serializer::encoders::PhoneEncoder;
 use crate::serializer::encoders::{
-    DateEncoder, DateTimeEncoder, LazyEncoder, TEncoder, TimeEncoder,
+    BytesEncoder, CodeEncoder, CustomEncoder, DateEncoder, DateTimeEncoder, IpEncoder,
+    JsonStringEncoder, LazyEncoder, PathEncoder, PrefixedIntEncoder, PydanticModelEncoder,
+    TEncoder, TimeDurationEncoder, TimeEncoder,
 };
+use crate::serializer::iputil::{IpKind, IpVersion};
+use crate::serializer::localeutil::CodeFormat;
+use crate::serializer::panics::catch_panics;
+use crate::serializer::semverutil::Version;
 use atomic_refcell::AtomicRefCell;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyString, PyTuple};
-use pyo3::{AsPyPointer, PyAny, PyResult};
+use pyo3::types::{PyFrozenSet, PyLong, PyString, PyTuple};
+use pyo3::{AsPyPointer, Py, PyAny, PyResult};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -12,8 +26,10 @@ use super::py::is_not_set;
 use super::types::{get_object_type, Type};
 
 use super::encoders::{
-    ArrayEncoder, DecimalEncoder, DictionaryEncoder, EntityEncoder, EnumEncoder, Field,
-    NoopEncoder, OptionalEncoder, Serializer, TupleEncoder, UUIDEncoder,
+    ArrayEncoder, DecimalEncoder, DictionaryEncoder, EntityEncoder, EnumDumpFormat, EnumEncoder,
+    Field, FlagEncoder, MoneyEncoder, NoopEncoder, OptionalEncoder, ScalarField, SecretEncoder,
+    SemVerEncoder, Serializer, SetEncoder, SmallEntityEncoder, StringEncoder, TupleEncoder,
+    UUIDEncoder, UuidFormat, VariadicTupleEncoder,
 };
 
 type EncoderStateValue = Arc<AtomicRefCell<Option<EntityEncoder>>>;
@@ -22,10 +38,29 @@ type EncoderStateValue = Arc<AtomicRefCell<Option<EntityEncoder>>>;
 pub fn make_encoder(type_info: &PyAny) -> PyResult<Serializer> {
     let obj_type = get_object_type(type_info)?;
     let mut encoder_state: HashMap<usize, EncoderStateValue> = HashMap::new();
-    let serializer = Serializer {
-        encoder: get_encoder(type_info.py(), obj_type, &mut encoder_state)?,
-    };
-    Ok(serializer)
+    let encoder = catch_panics("make_encoder", || {
+        get_encoder(type_info.py(), obj_type, &mut encoder_state)
+    })?;
+    Ok(Serializer { encoder })
+}
+
+/// Parses an ISO 8601 duration (`P1DT2H`) into `datetime.timedelta`.
+#[pyfunction]
+#[pyo3(name = "parse_duration")]
+pub fn py_parse_duration(py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let delta = catch_panics("parse_duration", || parse_duration(value))?;
+    Ok(unsafe { PyObject::from_owned_ptr(py, delta) })
+}
+
+/// Formats `datetime.timedelta` as an ISO 8601 duration.
+#[pyfunction]
+#[pyo3(name = "dump_duration")]
+pub fn py_dump_duration(value: &PyAny) -> PyResult<String> {
+    catch_panics("dump_duration", || {
+        let mut result = String::new();
+        format_duration(duration_to_micros(value.as_ptr())?, &mut result);
+        Ok(result)
+    })
 }
 
 pub fn get_encoder(
@@ -34,10 +69,42 @@ pub fn get_encoder(
     encoder_state: &mut HashMap<usize, EncoderStateValue>,
 ) -> PyResult<Box<TEncoder>> {
     let encoder: Box<TEncoder> = match obj_type {
-        Type::String | Type::Integer | Type::Bytes | Type::Float | Type::Boolean | Type::Any => {
-            Box::new(NoopEncoder)
+        Type::Float | Type::Boolean | Type::Any => Box::new(NoopEncoder),
+        Type::String(type_info) => get_string_encoder(py, type_info)?,
+        Type::Integer(type_info) => match type_info.getattr(py, "prefixed")?.extract::<bool>(py)? {
+            true => Box::new(PrefixedIntEncoder {
+                min: get_optional_attr(py, &type_info, "min")?,
+                max: get_optional_attr(py, &type_info, "max")?,
+                exclusive_min: get_optional_attr(py, &type_info, "exclusive_min")?,
+                exclusive_max: get_optional_attr(py, &type_info, "exclusive_max")?,
+                multiple_of: get_optional_attr(py, &type_info, "multiple_of")?,
+            }),
+            false => Box::new(NoopEncoder),
+        },
+        Type::Bytes(type_info) => {
+            let encoding = type_info
+                .getattr(py, "encoding")?
+                .getattr(py, "value")?
+                .extract::<String>(py)?;
+            match BytesEncoding::from_name(&encoding) {
+                Some(encoding) => Box::new(BytesEncoder { encoding }),
+                None => Box::new(NoopEncoder),
+            }
+        }
+        Type::Decimal(type_info) => Box::new(DecimalEncoder {
+            min: get_optional_attr(py, &type_info, "min")?,
+            max: get_optional_attr(py, &type_info, "max")?,
+            exclusive_min: get_optional_attr(py, &type_info, "exclusive_min")?,
+            exclusive_max: get_optional_attr(py, &type_info, "exclusive_max")?,
+            multiple_of: get_optional_attr(py, &type_info, "multiple_of")?,
+            max_digits: type_info.getattr(py, "max_digits")?.extract(py)?,
+            places: type_info.getattr(py, "places")?.extract(py)?,
+        }),
+        Type::JsonString(type_info) => {
+            let inner = get_object_type(type_info.getattr(py, "inner")?.as_ref(py))?;
+            let encoder = get_encoder(py, inner, encoder_state)?;
+            Box::new(JsonStringEncoder { encoder })
         }
-        Type::Decimal => Box::new(DecimalEncoder),
         Type::Optional(type_info) => {
             let inner = get_object_type(type_info.getattr(py, "inner")?.as_ref(py))?;
             let encoder = get_encoder(py, inner, encoder_state)?;
@@ -47,19 +114,46 @@ pub fn get_encoder(
             let key_type = get_object_type(type_info.getattr(py, "key_type")?.as_ref(py))?;
             let value_type = get_object_type(type_info.getattr(py, "value_type")?.as_ref(py))?;
 
+            let int_keys = has_int_keys(py, &key_type)?;
             let key_encoder = get_encoder(py, key_type, encoder_state)?;
             let value_encoder = get_encoder(py, value_type, encoder_state)?;
 
             Box::new(DictionaryEncoder {
                 key_encoder,
                 value_encoder,
+                int_keys,
             })
         }
         Type::Array(type_info) => {
             let item_type = get_object_type(type_info.getattr(py, "item_type")?.as_ref(py))?;
             let encoder = get_encoder(py, item_type, encoder_state)?;
 
-            Box::new(ArrayEncoder { encoder })
+            let unique_items = type_info.getattr(py, "unique_items")?.extract(py)?;
+            let unique_by: Option<String> = type_info.getattr(py, "unique_by")?.extract(py)?;
+            let unique_by = unique_by.map(|field| PyString::new(py, &field).into());
+            let sorted_by: Option<(String, String)> =
+                type_info.getattr(py, "sorted_by")?.extract(py)?;
+            let sorted_by =
+                sorted_by.map(|(field, order)| (PyString::new(py, &field).into(), order == "desc"));
+
+            Box::new(ArrayEncoder {
+                encoder,
+                unique_items,
+                unique_by,
+                sorted_by,
+            })
+        }
+        Type::Set(type_info) => {
+            let item_type = get_object_type(type_info.getattr(py, "item_type")?.as_ref(py))?;
+            let encoder = get_encoder(py, item_type, encoder_state)?;
+            let is_frozen = type_info.getattr(py, "is_frozen")?.extract(py)?;
+            let unique_items = type_info.getattr(py, "unique_items")?.extract(py)?;
+
+            Box::new(SetEncoder {
+                encoder,
+                is_frozen,
+                unique_items,
+            })
         }
         Type::Tuple(type_info) => {
             let mut encoders = vec![];
@@ -68,24 +162,40 @@ pub fn get_encoder(
                 let encoder = get_encoder(py, get_object_type(item_type)?, encoder_state)?;
                 encoders.push(encoder);
             }
-            Box::new(TupleEncoder { encoders })
+            if type_info.getattr(py, "variadic")?.extract::<bool>(py)? {
+                Box::new(VariadicTupleEncoder {
+                    encoder: encoders.remove(0),
+                    min_length: type_info.getattr(py, "min_length")?.extract(py)?,
+                    max_length: type_info.getattr(py, "max_length")?.extract(py)?,
+                })
+            } else {
+                Box::new(TupleEncoder { encoders })
+            }
         }
         Type::Entity(type_info) => {
             let py_type = type_info.getattr(py, "cls")?;
             let class_fields = type_info.getattr(py, "fields")?;
             let mut fields = vec![];
+            let mut scalar_fields = true;
 
             for field in class_fields.as_ref(py).iter()? {
                 let field = field?;
                 let f_name: &PyString = field.getattr("name")?.downcast()?;
                 let dict_key: &PyString = field.getattr("dict_key")?.downcast()?;
+                let load_key: Option<&PyString> = field.getattr("load_key")?.extract()?;
+                let dump_key: Option<&PyString> = field.getattr("dump_key")?.extract()?;
+                let init_name: Option<&PyString> = field.getattr("init_name")?.extract()?;
+                let aliases: Vec<&PyString> = field.getattr("aliases")?.extract()?;
                 let f_type = get_object_type(field.getattr("type")?)?;
+                scalar_fields &= is_scalar(py, &f_type)?;
                 let f_default = field.getattr("default")?;
                 let f_default_factory = field.getattr("default_factory")?;
 
                 let fld = Field {
                     name: f_name.into(),
-                    dict_key: dict_key.into(),
+                    load_key: load_key.unwrap_or(dict_key).into(),
+                    dump_key: dump_key.unwrap_or(dict_key).into(),
+                    aliases: aliases.into_iter().map(Into::into).collect(),
                     encoder: get_encoder(py, f_type, encoder_state)?,
                     default: match is_not_set(f_default)? {
                         true => None,
@@ -95,20 +205,37 @@ pub fn get_encoder(
                         true => None,
                         false => Some(f_default_factory.into()),
                     },
+                    skip_if_default: field.getattr("skip_if_default")?.extract()?,
+                    skip_if: field.getattr("skip_if")?.extract()?,
+                    is_property: field.getattr("is_property")?.extract()?,
+                    is_extra_keys: field.getattr("is_extra_keys")?.extract()?,
+                    init: field.getattr("init")?.extract()?,
+                    init_name: init_name.unwrap_or(f_name).into(),
+                    slot: field.getattr("slot")?.extract()?,
+                    error_message: field.getattr("error_message")?.extract()?,
                 };
                 fields.push(fld);
             }
 
             let create_new_object_args = PyTuple::new(py, vec![py_type]).into();
+            let post_load: Option<String> = type_info.getattr(py, "post_load")?.extract(py)?;
 
+            let consumed_keys = get_consumed_keys(py, &fields)?;
             let encoder = EntityEncoder {
                 create_new_object_args,
                 fields,
+                consumed_keys,
+                post_load: post_load.map(|method| PyString::new(py, &method).into()),
+                pre_dump: type_info.getattr(py, "pre_dump")?.extract(py)?,
+                use_init: type_info.getattr(py, "use_init")?.extract(py)?,
             };
             let python_object_id = type_info.as_ptr() as *const _ as usize;
             let val = encoder_state.entry(python_object_id).or_default();
             AtomicRefCell::<Option<EntityEncoder>>::borrow_mut(val).replace(encoder.clone());
-            Box::new(encoder)
+            match scalar_fields {
+                true => get_small_entity_encoder(&encoder).unwrap_or_else(|| Box::new(encoder)),
+                false => Box::new(encoder),
+            }
         }
         Type::RecursionHolder(type_info) => {
             let inner_type = type_info.call_method0(py, "get_type")?;
@@ -118,15 +245,325 @@ pub fn get_encoder(
                 inner: encoder.clone(),
             })
         }
-        Type::Uuid => Box::new(UUIDEncoder),
+        Type::Uuid(type_info) => {
+            let dump_format = type_info
+                .getattr(py, "dump_format")?
+                .getattr(py, "value")?
+                .extract::<String>(py)?;
+            match UuidFormat::from_name(&dump_format) {
+                Some(dump_format) => Box::new(UUIDEncoder {
+                    as_str: type_info.getattr(py, "as_str")?.extract(py)?,
+                    lenient: type_info.getattr(py, "lenient")?.extract(py)?,
+                    dump_format,
+                }),
+                None => {
+                    return Err(PyRuntimeError::new_err(format!(
+                        "Unsupported UUID format {dump_format}"
+                    )))
+                }
+            }
+        }
+        Type::Path(type_info) => Box::new(PathEncoder {
+            cls: type_info.getattr(py, "cls")?,
+            absolute: type_info.getattr(py, "absolute")?.extract(py)?,
+            allow_parent_refs: type_info.getattr(py, "allow_parent_refs")?.extract(py)?,
+        }),
+        Type::IpAddress(type_info) => get_ip_encoder(py, type_info, IpKind::Address)?,
+        Type::IpNetwork(type_info) => get_ip_encoder(py, type_info, IpKind::Network)?,
+        Type::IpInterface(type_info) => get_ip_encoder(py, type_info, IpKind::Interface)?,
+        Type::Money(type_info) => Box::new(MoneyEncoder {
+            cls: type_info.getattr(py, "cls")?,
+        }),
+        Type::Secret(type_info) => {
+            let format: Option<String> = type_info.getattr(py, "format")?.extract(py)?;
+            Box::new(SecretEncoder {
+                cls: type_info.getattr(py, "cls")?,
+                redact: type_info.getattr(py, "redact")?.extract(py)?,
+                placeholder: type_info.getattr(py, "placeholder")?.extract(py)?,
+                bytes: type_info.getattr(py, "is_bytes")?.extract(py)?,
+                jwt: format.as_deref() == Some("jwt"),
+            })
+        }
+        Type::SemVer(type_info) => Box::new(SemVerEncoder {
+            min: get_version_attr(py, &type_info, "min")?,
+            max: get_version_attr(py, &type_info, "max")?,
+        }),
+        Type::Custom(type_info) => Box::new(CustomEncoder {
+            encoder: type_info.getattr(py, "encoder")?,
+        }),
+        Type::PydanticModel(type_info) => Box::new(PydanticModelEncoder {
+            model: type_info.getattr(py, "cls")?,
+        }),
         Type::Enum(type_info) => {
             let py_type = type_info.getattr(py, "cls")?;
-            Box::new(EnumEncoder { enum_type: py_type })
+            let dump_format: String = type_info.getattr(py, "dump_format")?.extract(py)?;
+            let members = py_type
+                .as_ref(py)
+                .iter()?
+                .map(|member| {
+                    let member = member?;
+                    Ok((
+                        member.getattr("name")?.extract()?,
+                        member.getattr("value")?.str()?.extract()?,
+                    ))
+                })
+                .collect::<PyResult<_>>()?;
+            Box::new(EnumEncoder {
+                name: py_type.getattr(py, "__name__")?.extract(py)?,
+                enum_type: py_type,
+                members,
+                load_names: type_info.getattr(py, "load_names")?.extract(py)?,
+                load_values: type_info.getattr(py, "load_values")?.extract(py)?,
+                dump_format: EnumDumpFormat::from_name(&dump_format).ok_or_else(|| {
+                    PyRuntimeError::new_err(format!("Unsupported enum dump format {dump_format}"))
+                })?,
+            })
+        }
+        Type::Flag(type_info) => {
+            let py_type = type_info.getattr(py, "cls")?;
+            let mut members: Vec<(String, u64)> = vec![];
+            for member in py_type
+                .getattr(py, "__members__")?
+                .call_method0(py, "values")?
+                .as_ref(py)
+                .iter()?
+            {
+                let member = member?;
+                let bits: u64 = member.getattr("value")?.extract()?;
+                // aliases and combined members are dumped by their bits
+                if bits.is_power_of_two() && members.iter().all(|(_, b)| *b != bits) {
+                    members.push((member.getattr("name")?.extract()?, bits));
+                }
+            }
+            let dump_format: String = type_info.getattr(py, "dump_format")?.extract(py)?;
+            Box::new(FlagEncoder {
+                name: py_type.getattr(py, "__name__")?.extract(py)?,
+                flag_type: py_type,
+                all_bits: members.iter().fold(0, |all, (_, bits)| all | bits),
+                members,
+                dump_names: dump_format == "name",
+            })
+        }
+        Type::DateTime(type_info) => {
+            let formats: Vec<String> = type_info.getattr(py, "formats")?.extract(py)?;
+            Box::new(DateTimeEncoder {
+                formats: formats.into_iter().map(DateTimeFormat::from_name).collect(),
+                dump_format: type_info
+                    .getattr(py, "dump_format")?
+                    .extract::<Option<String>>(py)?
+                    .map(DateTimeFormat::from_name),
+                z_suffix: type_info.getattr(py, "z_suffix")?.extract(py)?,
+                precision: get_precision(py, &type_info)?,
+                require_aware: type_info.getattr(py, "require_aware")?.extract(py)?,
+                assume_timezone: get_optional_attr(py, &type_info, "assume_timezone")?,
+                convert_to_utc: type_info.getattr(py, "convert_to_utc")?.extract(py)?,
+                min: get_optional_attr(py, &type_info, "min")?,
+                max: get_optional_attr(py, &type_info, "max")?,
+                clamp_year: type_info.getattr(py, "clamp_year")?.extract(py)?,
+            })
+        }
+        Type::Time(type_info) => Box::new(TimeEncoder {
+            precision: get_precision(py, &type_info)?,
+            min: get_optional_attr(py, &type_info, "min")?,
+            max: get_optional_attr(py, &type_info, "max")?,
+        }),
+        Type::Date(type_info) => Box::new(DateEncoder {
+            lenient: type_info.getattr(py, "lenient")?.extract(py)?,
+            min: get_optional_attr(py, &type_info, "min")?,
+            max: get_optional_attr(py, &type_info, "max")?,
+            clamp_year: type_info.getattr(py, "clamp_year")?.extract(py)?,
+        }),
+        Type::TimeDuration(type_info) => {
+            let format = type_info
+                .getattr(py, "format")?
+                .getattr(py, "value")?
+                .extract::<String>(py)?;
+            match DurationFormat::from_name(&format) {
+                Some(format) => Box::new(TimeDurationEncoder { format }),
+                None => {
+                    return Err(PyRuntimeError::new_err(format!(
+                        "Unsupported duration format {format}"
+                    )))
+                }
+            }
         }
-        Type::DateTime => Box::new(DateTimeEncoder),
-        Type::Time => Box::new(TimeEncoder),
-        Type::Date => Box::new(DateEncoder),
     };
 
     Ok(encoder)
 }
+
+// bounds are checked by _constraints before the encoder is built
+fn get_version_attr(
+    py: Python<'_>,
+    type_info: &Py<PyAny>,
+    name: &str,
+) -> PyResult<Option<Version>> {
+    let value: Option<String> = type_info.getattr(py, name)?.extract(py)?;
+    value
+        .map(|value| {
+            Version::parse(&value)
+                .ok_or_else(|| PyValueError::new_err(format!("Invalid version {value:?}")))
+        })
+        .transpose()
+}
+
+fn get_consumed_keys(py: Python<'_>, fields: &[Field]) -> PyResult<Py<PyFrozenSet>> {
+    let keys: Vec<&Py<PyString>> = fields
+        .iter()
+        .filter(|field| !field.is_property && !field.is_extra_keys)
+        .flat_map(|field| std::iter::once(&field.load_key).chain(&field.aliases))
+        .collect();
+    Ok(PyFrozenSet::new(py, &keys)?.into())
+}
+
+macro_rules! small_entity_encoder {
+    ($args:expr, $fields:expr, $($n:literal)*) => {
+        match $fields.len() {
+            $($n => Some(Box::new(SmallEntityEncoder::<$n> {
+                create_new_object_args: $args,
+                fields: $fields.try_into().ok()?,
+            }) as Box<TEncoder>),)*
+            _ => None,
+        }
+    };
+}
+
+/// Entities of up to 8 scalar fields, see `is_scalar`, get an encoder specialized for their field count
+/// unless a field or the class needs the generic EntityEncoder.
+fn get_small_entity_encoder(encoder: &EntityEncoder) -> Option<Box<TEncoder>> {
+    if encoder.post_load.is_some() || encoder.pre_dump.is_some() || encoder.use_init {
+        return None;
+    }
+    let mut fields = vec![];
+    for field in &encoder.fields {
+        if !field.aliases.is_empty()
+            || field.default.is_some()
+            || field.default_factory.is_some()
+            || field.skip_if_default
+            || field.skip_if.is_some()
+            || field.is_property
+            || field.is_extra_keys
+            || field.slot.is_some()
+            || field.error_message.is_some()
+        {
+            return None;
+        }
+        fields.push(ScalarField {
+            name: field.name.clone(),
+            load_key: field.load_key.clone(),
+            dump_key: field.dump_key.clone(),
+        });
+    }
+    let args = encoder.create_new_object_args.clone();
+    small_entity_encoder!(args, fields, 1 2 3 4 5 6 7 8)
+}
+
+// Types loaded and dumped by NoopEncoder, their values are copied as is.
+fn is_scalar(py: Python<'_>, field_type: &Type) -> PyResult<bool> {
+    match field_type {
+        Type::Float | Type::Boolean => Ok(true),
+        Type::Integer(type_info) => Ok(!type_info.getattr(py, "prefixed")?.extract::<bool>(py)?),
+        Type::String(type_info) => {
+            for attr in ["strip_whitespace", "to_lower", "to_upper"] {
+                if type_info.getattr(py, attr)?.extract::<bool>(py)? {
+                    return Ok(false);
+                }
+            }
+            Ok(type_info.getattr(py, "format")?.is_none(py))
+        }
+        _ => Ok(false),
+    }
+}
+
+fn get_string_encoder(py: Python<'_>, type_info: Py<PyAny>) -> PyResult<Box<TEncoder>> {
+    let format: Option<String> = type_info.getattr(py, "format")?.extract(py)?;
+    let encoder: Box<TEncoder> = match format.as_deref() {
+        None => Box::new(NoopEncoder),
+        #[cfg(feature = "phone")]
+        Some("phone") => Box::new(PhoneEncoder),
+        #[cfg(not(feature = "phone"))]
+        Some("phone") => {
+            return Err(PyRuntimeError::new_err(
+                "string format 'phone' requires serpyco-rs built with the 'phone' feature",
+            ))
+        }
+        Some(name) => match CodeFormat::from_name(name) {
+            Some(format) => Box::new(CodeEncoder { format }),
+            None => {
+                return Err(PyRuntimeError::new_err(format!(
+                    "Unsupported string format {:?}",
+                    name
+                )))
+            }
+        },
+    };
+    let strip_whitespace: bool = type_info.getattr(py, "strip_whitespace")?.extract(py)?;
+    let to_lower: bool = type_info.getattr(py, "to_lower")?.extract(py)?;
+    let to_upper: bool = type_info.getattr(py, "to_upper")?.extract(py)?;
+    if !strip_whitespace && !to_lower && !to_upper {
+        return Ok(encoder);
+    }
+    Ok(Box::new(StringEncoder {
+        strip_whitespace,
+        to_lower,
+        to_upper,
+        min_length: type_info.getattr(py, "min_length")?.extract(py)?,
+        max_length: type_info.getattr(py, "max_length")?.extract(py)?,
+        encoder,
+    }))
+}
+
+fn get_ip_encoder(py: Python<'_>, type_info: Py<PyAny>, kind: IpKind) -> PyResult<Box<TEncoder>> {
+    let version = type_info.getattr(py, "version")?.extract::<u8>(py)?;
+    match IpVersion::from_number(version) {
+        Some(version) => Ok(Box::new(IpEncoder {
+            cls: type_info.getattr(py, "cls")?,
+            version,
+            kind,
+        })),
+        None => Err(PyRuntimeError::new_err(format!(
+            "Unsupported IP version {version}"
+        ))),
+    }
+}
+
+// JSON object keys are always strings, int and int-valued enum keys are parsed back on load,
+// unless enum keys are loaded by name.
+fn has_int_keys(py: Python<'_>, key_type: &Type) -> PyResult<bool> {
+    match key_type {
+        Type::Integer(_) => Ok(true),
+        Type::Enum(type_info) => {
+            if type_info.getattr(py, "load_names")?.extract::<bool>(py)? {
+                return Ok(false);
+            }
+            for member in type_info.getattr(py, "cls")?.as_ref(py).iter()? {
+                if !member?.getattr("value")?.is_instance_of::<PyLong>()? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+fn get_precision(py: Python<'_>, type_info: &Py<PyAny>) -> PyResult<Option<SubsecondPrecision>> {
+    let precision = match get_optional_attr(py, type_info, "precision")? {
+        Some(precision) => precision.getattr(py, "value")?.extract::<String>(py)?,
+        None => return Ok(None),
+    };
+    let round = type_info.getattr(py, "round_precision")?.extract(py)?;
+    Ok(SubsecondPrecision::from_name(&precision, round))
+}
+
+fn get_optional_attr(
+    py: Python<'_>,
+    type_info: &Py<PyAny>,
+    attr_name: &str,
+) -> PyResult<Option<Py<PyAny>>> {
+    let value = type_info.getattr(py, attr_name)?;
+    Ok(match value.is_none(py) {
+        true => None,
+        false => Some(value),
+    })
+}
@@ -0,0 +1,182 @@
+use std::cell::RefCell;
+
+use pyo3::types::{IntoPyDict, PyString, PyTuple};
+use pyo3::{IntoPy, Py, PyAny, PyErr, PyResult, Python};
+use pyo3_ffi::PyObject;
+
+use super::cancel::Limits;
+use super::encoders::{ConstraintError, RequiredFieldError, TypeMismatchError, ValidationError};
+
+/// Longest `{value}` of an ErrorMessage template, longer reprs are cut.
+const MAX_VALUE_REPR: usize = 80;
+
+/// Options of a single `Serializer.load` call, passed down to the encoders.
+#[derive(Debug, Default)]
+pub struct LoadOptions {
+    /// Bounds, length and item checks are skipped.
+    pub trusted: bool,
+    /// Absent entity fields are loaded as UNSET.
+    pub partial: bool,
+    pub limits: Option<Limits>,
+    /// The innermost entity field a ValidationError passed, the outer fields keep its message.
+    pub failed_field: RefCell<Option<FailedField>>,
+    /// Instance path of a ValidationError, innermost key first.
+    pub error_path: RefCell<Vec<PathKey>>,
+}
+
+/// A segment of the instance path, indexes are ints in the `path` of the raised error.
+#[derive(Debug)]
+pub enum PathKey {
+    Index(isize),
+    Key(String),
+}
+
+impl PathKey {
+    fn to_object(&self, py: Python<'_>) -> pyo3::PyObject {
+        match self {
+            PathKey::Index(index) => index.into_py(py),
+            PathKey::Key(key) => key.into_py(py),
+        }
+    }
+}
+
+impl std::fmt::Display for PathKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathKey::Index(index) => write!(f, "{}", index),
+            PathKey::Key(key) => f.write_str(key),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FailedField {
+    template: Option<Py<PyString>>,
+    value: Py<PyAny>,
+}
+
+impl LoadOptions {
+    #[inline]
+    pub fn check_limits(&self) -> PyResult<()> {
+        match &self.limits {
+            Some(limits) => limits.check(),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    pub fn check_limits_now(&self) -> PyResult<()> {
+        match &self.limits {
+            Some(limits) => limits.check_now(),
+            None => Ok(()),
+        }
+    }
+
+    /// Records the key of the value that failed to load, for ErrorMessage templates and LoadTimeout.
+    #[inline]
+    pub fn with_path(&self, e: PyErr, key: impl FnOnce() -> PathKey) -> PyErr {
+        if Python::with_gil(|py| is_encoder_error(py, &e)) {
+            self.error_path.borrow_mut().push(key());
+            return e;
+        }
+        match &self.limits {
+            Some(limits) => limits.with_timeout_path(e, || key().to_string()),
+            None => e,
+        }
+    }
+
+    /// Like `with_path`, the innermost field also keeps its ErrorMessage template for `field_error`.
+    #[inline]
+    pub fn with_field_path(
+        &self,
+        e: PyErr,
+        key: &Py<PyString>,
+        template: Option<&Py<PyString>>,
+        value: *mut PyObject,
+    ) -> PyErr {
+        let e = self.with_path(e, || PathKey::Key(key.to_string()));
+        let mut failed_field = self.failed_field.borrow_mut();
+        if failed_field.is_none() {
+            Python::with_gil(|py| {
+                if is_encoder_error(py, &e) {
+                    *failed_field = Some(FailedField {
+                        template: template.cloned(),
+                        value: unsafe { Py::from_borrowed_ptr(py, value) },
+                    });
+                }
+            });
+        }
+        e
+    }
+
+    /// Sets the instance path of a ValidationError as its `path` attribute, a tuple of keys and indexes.
+    /// The message is replaced with the ErrorMessage template of the field that failed, formatted with
+    /// message, instance_path, value and expected like schema errors, expected is None.
+    /// An unknown placeholder or a stray brace keeps the original message.
+    pub fn field_error(&self, e: PyErr) -> PyErr {
+        let mut path = self.error_path.take();
+        path.reverse();
+        let failed_field = self.failed_field.take();
+        Python::with_gil(|py| {
+            if !is_encoder_error(py, &e) {
+                return e;
+            }
+            let e = match failed_field {
+                Some(FailedField {
+                    template: Some(template),
+                    value,
+                }) => format_field_error(py, e, &path, &template, &value),
+                _ => e,
+            };
+            let path = PyTuple::new(py, path.iter().map(|key| key.to_object(py)));
+            match e.value(py).setattr("path", path) {
+                Ok(()) => e,
+                Err(err) => err,
+            }
+        })
+    }
+}
+
+fn format_field_error(
+    py: Python<'_>,
+    e: PyErr,
+    path: &[PathKey],
+    template: &Py<PyString>,
+    value: &Py<PyAny>,
+) -> PyErr {
+    let format = || -> PyResult<PyErr> {
+        let mut value = value.as_ref(py).repr()?.to_string();
+        if let Some((end, _)) = value.char_indices().nth(MAX_VALUE_REPR) {
+            value.truncate(end);
+            value.push_str("...");
+        }
+        let instance_path: Vec<String> = path.iter().map(|key| key.to_string()).collect();
+        let kwargs = [
+            ("message", e.value(py).to_string().into_py(py)),
+            ("instance_path", instance_path.join("/").into_py(py)),
+            ("value", value.into_py(py)),
+            ("expected", py.None()),
+        ]
+        .into_py_dict(py);
+        let message: String = template
+            .call_method(py, "format", (), Some(kwargs))?
+            .extract(py)?;
+        Ok(PyErr::from_type(e.get_type(py), message))
+    };
+    match format() {
+        Ok(error) => {
+            error.set_cause(py, Some(e));
+            error
+        }
+        Err(_) => e,
+    }
+}
+
+/// Errors of the encoders, SchemaValidationError of item checks has its own messages.
+fn is_encoder_error(py: Python<'_>, e: &PyErr) -> bool {
+    let error_type = e.get_type(py);
+    error_type.is(py.get_type::<ValidationError>())
+        || error_type.is(py.get_type::<RequiredFieldError>())
+        || error_type.is(py.get_type::<TypeMismatchError>())
+        || error_type.is(py.get_type::<ConstraintError>())
+}
@@ -0,0 +1,20 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::PyResult;
+
+/// Runs `f`, a panic is raised as `RuntimeError` naming the entry point.
+/// pyo3 raises `PanicException`, which isn't an `Exception` and escapes `except Exception`.
+pub fn catch_panics<T>(context: &str, f: impl FnOnce() -> PyResult<T>) -> PyResult<T> {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let reason = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown reason");
+        Err(PyRuntimeError::new_err(format!(
+            "{} panicked: {}",
+            context, reason
+        )))
+    })
+}
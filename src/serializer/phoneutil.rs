@@ -0,0 +1,33 @@
+use pyo3::{PyErr, PyResult};
+
+use super::encoders::ValidationError;
+
+/// Normalizes a phone number in international format to E.164 (`+14155550123`).
+/// Spaces, dashes, dots and parentheses are dropped, `00` is accepted instead of `+`.
+pub fn normalize_phone(value: &str) -> PyResult<String> {
+    let trimmed = value.trim();
+    let number = match trimmed.strip_prefix('+') {
+        Some(number) => number,
+        None => trimmed
+            .strip_prefix("00")
+            .ok_or_else(|| invalid_phone(value))?,
+    };
+    let mut result = String::with_capacity(16);
+    result.push('+');
+    for c in number.chars() {
+        match c {
+            '0'..='9' => result.push(c),
+            ' ' | '-' | '.' | '(' | ')' => {}
+            _ => return Err(invalid_phone(value)),
+        }
+    }
+    // country codes never start with 0, E.164 numbers have at most 15 digits
+    match result.len() - 1 {
+        7..=15 if !result[1..].starts_with('0') => Ok(result),
+        _ => Err(invalid_phone(value)),
+    }
+}
+
+fn invalid_phone(value: &str) -> PyErr {
+    ValidationError::new_err(format!("{:?} is not a valid phone number", value))
+}
@@ -0,0 +1,57 @@
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use pyo3::types::PyDict;
+use pyo3::{pyfunction, Py, PyResult, Python};
+use pyo3_ffi::PyObject;
+
+use super::macros::ffi;
+use super::py::from_ptr_or_err;
+
+// the counters are shared by all threads, so they're only kept in debug builds
+// to stay off the dump hot path of release builds
+
+/// Dicts created with a size hint.
+#[cfg(debug_assertions)]
+static PRESIZED: AtomicU64 = AtomicU64::new(0);
+/// Presized dicts that got more items than the hint and had to grow.
+#[cfg(debug_assertions)]
+static OUTGROWN: AtomicU64 = AtomicU64::new(0);
+
+/// Creates a dict with room for `size_hint` items.
+#[inline]
+pub fn new_dict(size_hint: usize) -> PyResult<*mut PyObject> {
+    #[cfg(debug_assertions)]
+    PRESIZED.fetch_add(1, Ordering::Relaxed);
+    #[cfg(all(not(PyPy), not(Py_LIMITED_API)))]
+    let dict = ffi!(_PyDict_NewPresized(size_hint as pyo3_ffi::Py_ssize_t));
+    #[cfg(any(PyPy, Py_LIMITED_API))]
+    let dict = {
+        let _ = size_hint;
+        ffi!(PyDict_New())
+    };
+    from_ptr_or_err(dict)
+}
+
+/// Records whether the filled dict outgrew the size it was created with.
+#[inline]
+pub fn check_size_hint(dict: *mut PyObject, size_hint: usize) {
+    #[cfg(debug_assertions)]
+    if ffi!(PyDict_Size(dict)) as usize > size_hint {
+        OUTGROWN.fetch_add(1, Ordering::Relaxed);
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = (dict, size_hint);
+}
+
+/// Counters of presized dicts created by dump and load, empty in release builds.
+#[pyfunction]
+pub fn presize_stats(py: Python<'_>) -> PyResult<Py<PyDict>> {
+    let stats = PyDict::new(py);
+    #[cfg(debug_assertions)]
+    {
+        stats.set_item("presized", PRESIZED.load(Ordering::Relaxed))?;
+        stats.set_item("outgrown", OUTGROWN.load(Ordering::Relaxed))?;
+    }
+    Ok(stats.into())
+}
@@ -1,7 +1,8 @@
 use crate::serializer::macros::{call_method, ffi};
 use crate::serializer::types::{DECIMAL_PY_TYPE, ITEMS_STR, NOT_SET, PY_OBJECT__NEW__};
-use pyo3::types::PyTuple;
-use pyo3::{ffi, AsPyPointer, PyAny, PyErr, PyResult, Python};
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyLong, PyTuple};
+use pyo3::{ffi, AsPyPointer, IntoPyPointer, PyAny, PyErr, PyResult, Python};
 use pyo3_ffi::Py_ssize_t;
 use std::ffi::CString;
 use std::os::raw::{c_char, c_int};
@@ -37,6 +38,46 @@ pub fn obj_to_str(obj: *mut ffi::PyObject) -> PyResult<*mut ffi::PyObject> {
     from_ptr_or_err(ffi!(PyObject_Str(obj)))
 }
 
+#[inline]
+pub fn py_object_to_string(obj: *mut ffi::PyObject) -> String {
+    Python::with_gil(|py| unsafe { py.from_borrowed_ptr::<PyAny>(obj) }.to_string())
+}
+
+#[inline]
+pub fn py_number_remainder(
+    obj: *mut ffi::PyObject,
+    divisor: *mut ffi::PyObject,
+) -> PyResult<*mut ffi::PyObject> {
+    from_ptr_or_err(ffi!(PyNumber_Remainder(obj, divisor)))
+}
+
+#[inline]
+pub fn py_object_is_true(obj: *mut ffi::PyObject) -> PyResult<bool> {
+    let ret = ffi!(PyObject_IsTrue(obj));
+    error_on_minusone(ret)?;
+    Ok(ret == 1)
+}
+
+#[inline]
+pub fn py_object_rich_compare(
+    obj: *mut ffi::PyObject,
+    other: *mut ffi::PyObject,
+    op: CompareOp,
+) -> PyResult<bool> {
+    let ret = ffi!(PyObject_RichCompareBool(obj, other, op as c_int));
+    error_on_minusone(ret)?;
+    Ok(ret == 1)
+}
+
+#[inline]
+pub fn py_object_hash(obj: *mut ffi::PyObject) -> PyResult<ffi::Py_hash_t> {
+    let hash = ffi!(PyObject_Hash(obj));
+    if hash == -1 {
+        return Err(Python::with_gil(PyErr::fetch));
+    }
+    Ok(hash)
+}
+
 pub fn to_py_string(s: &str) -> *mut ffi::PyObject {
     let c_str = CString::new(s).unwrap();
     let c_world: *const c_char = c_str.as_ptr() as *const c_char;
@@ -44,7 +85,7 @@ pub fn to_py_string(s: &str) -> *mut ffi::PyObject {
 }
 
 #[inline]
-fn py_object_call1_or_err(
+pub fn py_object_call1_or_err(
     obj: *mut ffi::PyObject,
     args: *mut ffi::PyObject,
 ) -> PyResult<*mut ffi::PyObject> {
@@ -80,6 +121,39 @@ pub fn py_object_set_attr(
     error_on_minusone(ret)
 }
 
+/// Sets a `__slots__` attribute through its member descriptor, bypassing `__setattr__`.
+/// Objects that aren't instances of the descriptor's class are set by name.
+#[inline]
+pub fn py_slot_set(
+    slot: *mut ffi::PyObject,
+    obj: *mut ffi::PyObject,
+    value: *mut ffi::PyObject,
+) -> PyResult<()> {
+    let descr = slot as *mut ffi::PyDescrObject;
+    let set = unsafe { (*ffi::Py_TYPE(slot)).tp_descr_set };
+    match set {
+        Some(set) if ffi!(PyObject_TypeCheck(obj, (*descr).d_type)) != 0 => {
+            error_on_minusone(unsafe { set(slot, obj, value) })
+        }
+        _ => py_object_set_attr(obj, unsafe { (*descr).d_name }, value),
+    }
+}
+
+#[inline]
+pub fn py_slot_get(
+    slot: *mut ffi::PyObject,
+    obj: *mut ffi::PyObject,
+) -> PyResult<*mut ffi::PyObject> {
+    let descr = slot as *mut ffi::PyDescrObject;
+    let get = unsafe { (*ffi::Py_TYPE(slot)).tp_descr_get };
+    match get {
+        Some(get) if ffi!(PyObject_TypeCheck(obj, (*descr).d_type)) != 0 => {
+            from_ptr_or_err(unsafe { get(slot, obj, ffi::Py_TYPE(obj).cast()) })
+        }
+        _ => py_object_get_attr(obj, unsafe { (*descr).d_name }),
+    }
+}
+
 #[inline]
 pub fn py_str_to_str(obj: *mut ffi::PyObject) -> PyResult<&'static str> {
     let utf8_slice = {
@@ -107,6 +181,58 @@ pub fn py_str_to_str(obj: *mut ffi::PyObject) -> PyResult<&'static str> {
     Ok(unsafe { std::str::from_utf8_unchecked(utf8_slice) })
 }
 
+#[inline]
+pub fn py_bytes_to_slice(obj: *mut ffi::PyObject) -> PyResult<&'static [u8]> {
+    let mut buffer: *mut c_char = std::ptr::null_mut();
+    let mut length: ffi::Py_ssize_t = 0;
+    error_on_minusone(ffi!(PyBytes_AsStringAndSize(obj, &mut buffer, &mut length)))?;
+    Ok(unsafe { std::slice::from_raw_parts(buffer as *const u8, length as usize) })
+}
+
+#[inline]
+pub fn py_bytes_from_slice(data: &[u8]) -> PyResult<*mut ffi::PyObject> {
+    from_ptr_or_err(ffi!(PyBytes_FromStringAndSize(
+        data.as_ptr() as *const c_char,
+        data.len() as ffi::Py_ssize_t
+    )))
+}
+
+#[inline]
+pub fn py_str_from_str(s: &str) -> PyResult<*mut ffi::PyObject> {
+    from_ptr_or_err(ffi!(PyUnicode_FromStringAndSize(
+        s.as_ptr() as *const c_char,
+        s.len() as ffi::Py_ssize_t
+    )))
+}
+
+#[inline]
+pub fn py_int_from_str(obj: *mut ffi::PyObject, base: c_int) -> PyResult<*mut ffi::PyObject> {
+    // the builtin int(obj, base)
+    Python::with_gil(|py| {
+        let obj = unsafe { py.from_borrowed_ptr::<PyAny>(obj) };
+        Ok(py.get_type::<PyLong>().call1((obj, base))?.into_ptr())
+    })
+}
+
+#[inline]
+pub fn py_int_from_i64(value: i64) -> PyResult<*mut ffi::PyObject> {
+    from_ptr_or_err(ffi!(PyLong_FromLongLong(value)))
+}
+
+#[inline]
+pub fn py_float_as_f64(obj: *mut ffi::PyObject) -> PyResult<f64> {
+    let value = ffi!(PyFloat_AsDouble(obj));
+    if value == -1.0 && !ffi!(PyErr_Occurred()).is_null() {
+        return Err(Python::with_gil(PyErr::fetch));
+    }
+    Ok(value)
+}
+
+#[inline]
+pub fn py_float_from_f64(value: f64) -> PyResult<*mut ffi::PyObject> {
+    from_ptr_or_err(ffi!(PyFloat_FromDouble(value)))
+}
+
 #[inline]
 pub fn py_tuple_get_item(obj: *mut ffi::PyObject, index: usize) -> PyResult<*mut ffi::PyObject> {
     from_ptr_or_err(ffi!(PyTuple_GetItem(obj, index as Py_ssize_t)))
@@ -127,7 +253,7 @@ pub fn iter_over_dict_items(obj: *mut ffi::PyObject) -> PyResult<PyObjectIterato
 }
 
 #[inline]
-fn to_iter(obj: *mut ffi::PyObject) -> PyResult<PyObjectIterator> {
+pub fn to_iter(obj: *mut ffi::PyObject) -> PyResult<PyObjectIterator> {
     let internal = PyObjectIterator(from_ptr_or_err(ffi!(PyObject_GetIter(obj)))?);
     Ok(internal)
 }
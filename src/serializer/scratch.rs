@@ -0,0 +1,33 @@
+use std::cell::RefCell;
+
+use pyo3::PyResult;
+use pyo3_ffi::PyObject;
+
+use super::py::py_str_from_str;
+
+/// Larger buffers are dropped after use, so one huge value doesn't stay allocated per thread.
+const MAX_RETAINED_CAPACITY: usize = 64 * 1024;
+
+thread_local! {
+    static BUFFER: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Makes a Python str from text written by `f` into a reused per-thread buffer.
+pub fn py_str_with_buffer(f: impl FnOnce(&mut String)) -> PyResult<*mut PyObject> {
+    BUFFER.with(|buffer| match buffer.try_borrow_mut() {
+        Ok(mut buffer) => {
+            buffer.clear();
+            f(&mut buffer);
+            let result = py_str_from_str(&buffer);
+            if buffer.capacity() > MAX_RETAINED_CAPACITY {
+                *buffer = String::new();
+            }
+            result
+        }
+        Err(_) => {
+            let mut buffer = String::new();
+            f(&mut buffer);
+            py_str_from_str(&buffer)
+        }
+    })
+}
@@ -0,0 +1,135 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyDict, PyModule};
+
+use super::encoders::Serializer;
+use super::main::make_encoder;
+use super::panics::catch_panics;
+
+// a module instead of a dict of globals, get_type_hints looks up the "Node" forward reference in sys.modules
+const CASES_MODULE: &str = "
+import dataclasses
+import datetime
+import decimal
+import typing
+import uuid
+
+from serpyco_rs.metadata import Base64
+
+
+@dataclasses.dataclass
+class Node:
+    value: int
+    next: typing.Optional['Node'] = None
+";
+
+/// Case name, type and value, the type and value are evaluated in `CASES_MODULE`.
+const CASES: &[(&str, &str, &str)] = &[
+    ("int", "int", "2 ** 100"),
+    ("float", "float", "-1.5e-300"),
+    ("bool", "bool", "True"),
+    ("str", "str", "'naïve ☃ 𝄞'"),
+    (
+        "bytes",
+        "typing.Annotated[bytes, Base64]",
+        "bytes(range(256))",
+    ),
+    ("decimal", "decimal.Decimal", "decimal.Decimal('-3.14')"),
+    ("uuid", "uuid.UUID", "uuid.UUID(int=2 ** 127 + 1)"),
+    (
+        "datetime",
+        "datetime.datetime",
+        "datetime.datetime(2024, 2, 29, 23, 59, 59, 999999, \
+         tzinfo=datetime.timezone(datetime.timedelta(hours=5, minutes=30)))",
+    ),
+    (
+        "timedelta",
+        "datetime.timedelta",
+        "datetime.timedelta(days=1, seconds=3661, microseconds=5)",
+    ),
+    ("optional", "typing.Optional[uuid.UUID]", "None"),
+    (
+        "union",
+        "typing.Union[None, decimal.Decimal]",
+        "decimal.Decimal('0.1')",
+    ),
+    (
+        "list",
+        "list[decimal.Decimal]",
+        "[decimal.Decimal('1'), decimal.Decimal('-0.5')]",
+    ),
+    (
+        "dict",
+        "dict[str, uuid.UUID]",
+        "{'a': uuid.UUID(int=1), 'b': uuid.UUID(int=2)}",
+    ),
+    ("recursion", "Node", "Node(1, Node(2, Node(3)))"),
+];
+
+/// Round trips values through serializers made by `make_encoder` from described types
+/// to check that the extension works with the running interpreter.
+/// Returns a dict of case name to the error message, or None if the case passed.
+#[pyfunction]
+pub fn self_check(py: Python<'_>) -> PyResult<Py<PyDict>> {
+    catch_panics("self_check", || run_cases(py))
+}
+
+fn run_cases(py: Python<'_>) -> PyResult<Py<PyDict>> {
+    let module = PyModule::from_code(
+        py,
+        CASES_MODULE,
+        "serpyco_rs_self_check.py",
+        "serpyco_rs_self_check",
+    )?;
+    let describe_type = py
+        .import("serpyco_rs._describe")?
+        .getattr("describe_type")?;
+    let globals = module.dict();
+
+    let report = PyDict::new(py);
+    for (name, type_expression, value_expression) in CASES {
+        let error = run_case(
+            py,
+            describe_type,
+            globals,
+            type_expression,
+            value_expression,
+        )
+        .err()
+        .map(|e| e.to_string());
+        report.set_item(name, error)?;
+    }
+    Ok(report.into())
+}
+
+fn run_case(
+    py: Python<'_>,
+    describe_type: &PyAny,
+    globals: &PyDict,
+    type_expression: &str,
+    value_expression: &str,
+) -> PyResult<()> {
+    let type_info = describe_type.call1((py.eval(type_expression, Some(globals), None)?,))?;
+    let serializer = make_encoder(type_info)?;
+    let value = py.eval(value_expression, Some(globals), None)?;
+    round_trip(py, &serializer, value)
+}
+
+/// Dumps the value, loads it back and checks that it dumps to the same data.
+fn round_trip(py: Python<'_>, serializer: &Serializer, value: &PyAny) -> PyResult<()> {
+    let data = serializer.dump(value, None, None)?;
+    let loaded = serializer.load(data.as_ref(py), false, None, None, false)?;
+    let dumped_again = serializer.dump(loaded.as_ref(py), None, None)?;
+    if !data
+        .as_ref(py)
+        .rich_compare(dumped_again, CompareOp::Eq)?
+        .is_true()?
+    {
+        return Err(PyValueError::new_err(format!(
+            "{:?} changed after a round trip",
+            value
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Semantic version 2.0.0, build metadata is validated but ignored in comparisons.
+#[derive(Debug, Clone)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: Vec<Identifier>,
+    text: String,
+}
+
+// numeric identifiers have lower precedence than alphanumeric ones
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Identifier {
+    Numeric(u64),
+    Alphanumeric(String),
+}
+
+impl Version {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (version, build) = match value.split_once('+') {
+            Some((version, build)) => (version, Some(build)),
+            None => (value, None),
+        };
+        if let Some(build) = build {
+            if !build.split('.').all(is_identifier) {
+                return None;
+            }
+        }
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (version, None),
+        };
+        let mut parts = core.split('.');
+        let major = parse_number(parts.next()?)?;
+        let minor = parse_number(parts.next()?)?;
+        let patch = parse_number(parts.next()?)?;
+        if parts.next().is_some() {
+            return None;
+        }
+        let pre = match pre {
+            Some(pre) => pre
+                .split('.')
+                .map(|part| match part.bytes().all(|b| b.is_ascii_digit()) {
+                    true => parse_number(part).map(Identifier::Numeric),
+                    false if is_identifier(part) => {
+                        Some(Identifier::Alphanumeric(part.to_string()))
+                    }
+                    false => None,
+                })
+                .collect::<Option<Vec<_>>>()?,
+            None => vec![],
+        };
+        Some(Self {
+            major,
+            minor,
+            patch,
+            pre,
+            text: value.to_string(),
+        })
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                // a pre-release is lower than the release itself
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self.pre.cmp(&other.pre),
+            })
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+fn parse_number(value: &str) -> Option<u64> {
+    match value.len() > 1 && value.starts_with('0') {
+        true => None,
+        false if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+            value.parse().ok()
+        }
+        false => None,
+    }
+}
+
+fn is_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
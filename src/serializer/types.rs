@@ -1,10 +1,12 @@
+use pyo3::exceptions::PyTypeError;
 use pyo3::ffi::PyObject;
 use pyo3::types::PyModule;
 use pyo3::Python;
-use pyo3::{AsPyPointer, Py, PyAny, PyResult};
+use pyo3::{AsPyPointer, IntoPyPointer, Py, PyAny, PyResult};
 use std::sync::Once;
 
 use crate::serializer::py::{py_object_get_attr, to_py_string};
+use crate::serializer::unset::{Unset, UNSET};
 
 pub static mut INTEGER_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut STRING_TYPE: *mut PyObject = 0 as *mut PyObject;
@@ -13,24 +15,51 @@ pub static mut FLOAT_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut DECIMAL_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut BOOLEAN_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut UUID_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut IP_ADDRESS_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut IP_NETWORK_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut IP_INTERFACE_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut MONEY_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut SEMVER_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut SECRET_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut PATH_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut TIME_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut DATETIME_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut DATE_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut TIME_DURATION_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut ENUM_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut FLAG_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut ENTITY_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut OPTIONAL_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut ARRAY_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut SET_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut DICTIONARY_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut TUPLE_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut JSON_STRING_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut ANY_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut CUSTOM_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut PYDANTIC_MODEL_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut RECURSION_HOLDER_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut NOT_SET: *mut PyObject = 0 as *mut PyObject;
+pub static mut AMOUNT_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut CURRENCY_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut HEX_STR: *mut PyObject = 0 as *mut PyObject;
 pub static mut ITEMS_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut DUMP_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut LOAD_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut NAME_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut GET_SECRET_VALUE_STR: *mut PyObject = 0 as *mut PyObject;
 pub static mut ISOFORMAT_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut IS_ABSOLUTE_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut PARTS_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut STRFTIME_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut UTCOFFSET_STR: *mut PyObject = 0 as *mut PyObject;
+pub static mut URN_STR: *mut PyObject = 0 as *mut PyObject;
 pub static mut VALUE_STR: *mut PyObject = 0 as *mut PyObject;
 pub static mut UUID_PY_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut NONE_PY_TYPE: *mut PyObject = 0 as *mut PyObject;
 pub static mut DECIMAL_PY_TYPE: *mut PyObject = 0 as *mut PyObject;
+pub static mut JSON_LOADS: *mut PyObject = 0 as *mut PyObject;
+pub static mut JSON_DUMPS: *mut PyObject = 0 as *mut PyObject;
 pub static mut PY_TUPLE_0: *mut PyObject = 0 as *mut PyObject;
 pub static mut PY_OBJECT__NEW__: *mut PyObject = 0 as *mut PyObject;
 
@@ -38,69 +67,110 @@ static INIT: Once = Once::new();
 
 #[derive(Clone, Debug)]
 pub enum Type {
-    Integer,
-    String,
-    Bytes,
+    Integer(Py<PyAny>),
+    String(Py<PyAny>),
+    Bytes(Py<PyAny>),
     Float,
-    Decimal,
+    Decimal(Py<PyAny>),
     Boolean,
-    Uuid,
-    Time,
-    DateTime,
-    Date,
+    Uuid(Py<PyAny>),
+    IpAddress(Py<PyAny>),
+    IpNetwork(Py<PyAny>),
+    IpInterface(Py<PyAny>),
+    Money(Py<PyAny>),
+    SemVer(Py<PyAny>),
+    Secret(Py<PyAny>),
+    Path(Py<PyAny>),
+    Time(Py<PyAny>),
+    DateTime(Py<PyAny>),
+    Date(Py<PyAny>),
+    TimeDuration(Py<PyAny>),
     Enum(Py<PyAny>),
+    Flag(Py<PyAny>),
     Entity(Py<PyAny>),
     Optional(Py<PyAny>),
     Array(Py<PyAny>),
+    Set(Py<PyAny>),
     Dictionary(Py<PyAny>),
     Tuple(Py<PyAny>),
+    JsonString(Py<PyAny>),
     RecursionHolder(Py<PyAny>),
+    Custom(Py<PyAny>),
+    PydanticModel(Py<PyAny>),
     Any,
 }
 
 pub fn get_object_type(type_info: &PyAny) -> PyResult<Type> {
     if check_type!(type_info, INTEGER_TYPE) {
-        Ok(Type::Integer)
+        Ok(Type::Integer(type_info.into()))
     } else if check_type!(type_info, STRING_TYPE) {
-        Ok(Type::String)
+        Ok(Type::String(type_info.into()))
     } else if check_type!(type_info, BYTES_TYPE) {
-        Ok(Type::Bytes)
+        Ok(Type::Bytes(type_info.into()))
     } else if check_type!(type_info, FLOAT_TYPE) {
         Ok(Type::Float)
     } else if check_type!(type_info, DECIMAL_TYPE) {
-        Ok(Type::Decimal)
+        Ok(Type::Decimal(type_info.into()))
     } else if check_type!(type_info, BOOLEAN_TYPE) {
         Ok(Type::Boolean)
     } else if check_type!(type_info, UUID_TYPE) {
-        Ok(Type::Uuid)
+        Ok(Type::Uuid(type_info.into()))
+    } else if check_type!(type_info, IP_ADDRESS_TYPE) {
+        Ok(Type::IpAddress(type_info.into()))
+    } else if check_type!(type_info, IP_NETWORK_TYPE) {
+        Ok(Type::IpNetwork(type_info.into()))
+    } else if check_type!(type_info, IP_INTERFACE_TYPE) {
+        Ok(Type::IpInterface(type_info.into()))
+    } else if check_type!(type_info, MONEY_TYPE) {
+        Ok(Type::Money(type_info.into()))
+    } else if check_type!(type_info, SEMVER_TYPE) {
+        Ok(Type::SemVer(type_info.into()))
+    } else if check_type!(type_info, SECRET_TYPE) {
+        Ok(Type::Secret(type_info.into()))
+    } else if check_type!(type_info, PATH_TYPE) {
+        Ok(Type::Path(type_info.into()))
     } else if check_type!(type_info, TIME_TYPE) {
-        Ok(Type::Time)
+        Ok(Type::Time(type_info.into()))
     } else if check_type!(type_info, DATETIME_TYPE) {
-        Ok(Type::DateTime)
+        Ok(Type::DateTime(type_info.into()))
     } else if check_type!(type_info, DATE_TYPE) {
-        Ok(Type::Date)
+        Ok(Type::Date(type_info.into()))
+    } else if check_type!(type_info, TIME_DURATION_TYPE) {
+        Ok(Type::TimeDuration(type_info.into()))
     } else if check_type!(type_info, ENUM_TYPE) {
         Ok(Type::Enum(type_info.into()))
+    } else if check_type!(type_info, FLAG_TYPE) {
+        Ok(Type::Flag(type_info.into()))
     } else if check_type!(type_info, ENTITY_TYPE) {
         Ok(Type::Entity(type_info.into()))
     } else if check_type!(type_info, OPTIONAL_TYPE) {
         Ok(Type::Optional(type_info.into()))
     } else if check_type!(type_info, ARRAY_TYPE) {
         Ok(Type::Array(type_info.into()))
+    } else if check_type!(type_info, SET_TYPE) {
+        Ok(Type::Set(type_info.into()))
     } else if check_type!(type_info, DICTIONARY_TYPE) {
         Ok(Type::Dictionary(type_info.into()))
     } else if check_type!(type_info, TUPLE_TYPE) {
         Ok(Type::Tuple(type_info.into()))
+    } else if check_type!(type_info, JSON_STRING_TYPE) {
+        Ok(Type::JsonString(type_info.into()))
     } else if check_type!(type_info, ANY_TYPE) {
         Ok(Type::Any)
+    } else if check_type!(type_info, CUSTOM_TYPE) {
+        Ok(Type::Custom(type_info.into()))
+    } else if check_type!(type_info, PYDANTIC_MODEL_TYPE) {
+        Ok(Type::PydanticModel(type_info.into()))
     } else if check_type!(type_info, RECURSION_HOLDER_TYPE) {
         Ok(Type::RecursionHolder(type_info.into()))
     } else {
-        todo!("py Error 'Unsupported type' {type_info}")
+        Err(PyTypeError::new_err(format!(
+            "Unsupported type {type_info}"
+        )))
     }
 }
 
-pub fn init(py: Python<'_>) {
+pub fn init(py: Python<'_>) -> PyResult<()> {
     INIT.call_once(|| unsafe {
         let describe = PyModule::import(py, "serpyco_rs._describe").unwrap();
         INTEGER_TYPE = get_attr_ptr!(describe, "IntegerType");
@@ -110,15 +180,29 @@ pub fn init(py: Python<'_>) {
         DECIMAL_TYPE = get_attr_ptr!(describe, "DecimalType");
         BOOLEAN_TYPE = get_attr_ptr!(describe, "BooleanType");
         UUID_TYPE = get_attr_ptr!(describe, "UUIDType");
+        IP_ADDRESS_TYPE = get_attr_ptr!(describe, "IPAddressType");
+        IP_NETWORK_TYPE = get_attr_ptr!(describe, "IPNetworkType");
+        IP_INTERFACE_TYPE = get_attr_ptr!(describe, "IPInterfaceType");
+        MONEY_TYPE = get_attr_ptr!(describe, "MoneyType");
+        SEMVER_TYPE = get_attr_ptr!(describe, "SemVerType");
+        SECRET_TYPE = get_attr_ptr!(describe, "SecretType");
+        PATH_TYPE = get_attr_ptr!(describe, "PathType");
         TIME_TYPE = get_attr_ptr!(describe, "TimeType");
         DATETIME_TYPE = get_attr_ptr!(describe, "DateTimeType");
         DATE_TYPE = get_attr_ptr!(describe, "DateType");
+        TIME_DURATION_TYPE = get_attr_ptr!(describe, "TimeDurationType");
         ENUM_TYPE = get_attr_ptr!(describe, "EnumType");
+        FLAG_TYPE = get_attr_ptr!(describe, "FlagType");
         ENTITY_TYPE = get_attr_ptr!(describe, "EntityType");
         OPTIONAL_TYPE = get_attr_ptr!(describe, "OptionalType");
         ARRAY_TYPE = get_attr_ptr!(describe, "ArrayType");
+        SET_TYPE = get_attr_ptr!(describe, "SetType");
         DICTIONARY_TYPE = get_attr_ptr!(describe, "DictionaryType");
         TUPLE_TYPE = get_attr_ptr!(describe, "TupleType");
+        JSON_STRING_TYPE = get_attr_ptr!(describe, "JsonStringType");
+        ANY_TYPE = get_attr_ptr!(describe, "AnyType");
+        CUSTOM_TYPE = get_attr_ptr!(describe, "CustomType");
+        PYDANTIC_MODEL_TYPE = get_attr_ptr!(describe, "PydanticModelType");
         RECURSION_HOLDER_TYPE = get_attr_ptr!(describe, "RecursionHolder");
         NOT_SET = get_attr_ptr!(describe, "NOT_SET");
 
@@ -134,12 +218,33 @@ pub fn init(py: Python<'_>) {
         let decimal = PyModule::import(py, "decimal").unwrap();
         DECIMAL_PY_TYPE = py_object_get_attr(decimal.as_ptr(), to_py_string("Decimal")).unwrap();
 
+        let json = PyModule::import(py, "json").unwrap();
+        JSON_LOADS = get_attr_ptr!(json, "loads");
+        JSON_DUMPS = get_attr_ptr!(json, "dumps");
+
+        AMOUNT_STR = to_py_string("amount");
+        CURRENCY_STR = to_py_string("currency");
+        HEX_STR = to_py_string("hex");
         ITEMS_STR = to_py_string("items");
+        DUMP_STR = to_py_string("dump");
+        LOAD_STR = to_py_string("load");
+        NAME_STR = to_py_string("name");
+        URN_STR = to_py_string("urn");
         VALUE_STR = to_py_string("value");
         ISOFORMAT_STR = to_py_string("isoformat");
+        GET_SECRET_VALUE_STR = to_py_string("get_secret_value");
+        STRFTIME_STR = to_py_string("strftime");
+        IS_ABSOLUTE_STR = to_py_string("is_absolute");
+        PARTS_STR = to_py_string("parts");
+        UTCOFFSET_STR = to_py_string("utcoffset");
 
         PY_TUPLE_0 = pyo3_ffi::PyTuple_New(0);
     });
+    if unsafe { UNSET.is_null() } {
+        let unset = Py::new(py, Unset)?;
+        unsafe { UNSET = unset.into_ptr() };
+    }
+    Ok(())
 }
 
 macro_rules! check_type {
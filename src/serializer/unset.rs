@@ -0,0 +1,33 @@
+use pyo3::ffi::PyObject;
+use pyo3::{pyclass, pymethods, PyAny, PyRef};
+
+pub static mut UNSET: *mut PyObject = 0 as *mut PyObject;
+
+/// Value of absent optional fields loaded with `keep_unset=True`, UNSET attributes aren't dumped.
+#[pyclass(module = "serpyco_rs")]
+#[derive(Debug)]
+pub struct Unset;
+
+#[pymethods]
+impl Unset {
+    fn __repr__(&self) -> &'static str {
+        "UNSET"
+    }
+
+    fn __bool__(&self) -> bool {
+        false
+    }
+
+    // pickled by name, copies are the singleton itself
+    fn __reduce__(&self) -> &'static str {
+        "UNSET"
+    }
+
+    fn __copy__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'p>(slf: PyRef<'p, Self>, _memo: &PyAny) -> PyRef<'p, Self> {
+        slf
+    }
+}
@@ -9,7 +9,20 @@ from uuid import UUID
 import pytest
 from serpyco_rs._describe import describe_type
 from serpyco_rs._json_schema import get_json_schema
-from serpyco_rs.metadata import CamelCase, Max, MaxLength, Min, MinLength
+from serpyco_rs.metadata import (
+    Aliases,
+    CamelCase,
+    ErrorMessage,
+    Examples,
+    ExtraKeys,
+    JsonString,
+    LenientUUID,
+    Max,
+    MaxLength,
+    Min,
+    MinLength,
+    PrefixedInt,
+)
 
 
 def test_to_json_schema():
@@ -69,8 +82,7 @@ def test_to_json_schema():
                         "type": "string",
                     },
                     "h": {
-                        "format": "regex",
-                        "pattern": "^[0-9]{4}-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9](\\.[0-9]+)?(([+-][0-9][0-9]:[0-9][0-9])|Z)?$",
+                        "pattern": "^[+-]?[0-9]{4,}-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9](\\.[0-9]+)?(([+-][0-9][0-9]:[0-9][0-9])|Z)?$",
                         "type": "string",
                     },
                     "i": {"enum": ["a"]},
@@ -166,3 +178,120 @@ def test_to_json_schema__recursive_type():
             }
         },
     }
+
+
+def test_to_json_schema__json_string():
+    schema = get_json_schema(describe_type(Annotated[list[int], JsonString()]))
+
+    assert schema == {
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "string",
+        "contentMediaType": "application/json",
+        "contentSchema": {"type": "array", "items": {"type": "integer"}},
+        "definitions": {},
+    }
+
+
+def test_to_json_schema__field_examples():
+    @dataclass
+    class Data:
+        x: Annotated[int, Examples((1, 2))]
+
+    schema = get_json_schema(describe_type(Data))
+
+    assert schema["definitions"][schema["$ref"].split("/")[-1]]["properties"] == {
+        "x": {"type": "integer", "examples": [1, 2]},
+    }
+
+
+def test_to_json_schema__examples__dumped():
+    @dataclass
+    class Data:
+        at: Annotated[datetime, Examples((datetime(2024, 1, 2, 3, 4, 5),))]
+        items: list[Annotated[Decimal, Examples((Decimal("1.5"),))]]
+
+    schema = get_json_schema(describe_type(Data))
+
+    properties = schema["definitions"][schema["$ref"].split("/")[-1]]["properties"]
+    assert properties["at"]["examples"] == ["2024-01-02T03:04:05"]
+    assert properties["items"]["items"]["examples"] == ["1.5"]
+
+
+def test_to_json_schema__field_error_message__not_in_schema():
+    @dataclass
+    class Data:
+        x: Annotated[int, ErrorMessage("Enter a number")]
+
+    schema = get_json_schema(describe_type(Data))
+
+    assert schema["definitions"][schema["$ref"].split("/")[-1]]["properties"] == {"x": {"type": "integer"}}
+
+
+def test_to_json_schema__extra_keys():
+    @dataclass
+    class Data:
+        x: int
+        extra: Annotated[dict[str, int], ExtraKeys()]
+
+    schema = get_json_schema(describe_type(Data))
+
+    definition = schema["definitions"][schema["$ref"].split("/")[-1]]
+    assert definition["properties"] == {"x": {"type": "integer"}}
+    assert definition["additionalProperties"] == {"type": "integer"}
+    assert definition["required"] == ["x"]
+
+
+def test_to_json_schema__aliases__required_by_any_key():
+    @dataclass
+    class Data:
+        quantity: Annotated[int, Aliases(("qty",))]
+        name: str
+        note: Annotated[str, Aliases(("comment",))] = ""
+
+    schema = get_json_schema(describe_type(Data))
+
+    definition = schema["definitions"][schema["$ref"].split("/")[-1]]
+    assert definition["required"] == ["name"]
+    assert definition["allOf"] == [{"anyOf": [{"required": ["quantity"]}, {"required": ["qty"]}]}]
+
+
+def test_to_json_schema__prefixed_int():
+    schema = get_json_schema(describe_type(Annotated[int, PrefixedInt(), Min(0)]))
+
+    assert schema == {
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "anyOf": [
+            {"type": "integer", "minimum": 0},
+            {"type": "string", "pattern": "^[+-]?(0[xX][0-9a-fA-F_]+|0[oO][0-7_]+|0[bB][01_]+|[0-9_]+)$"},
+        ],
+        "definitions": {},
+    }
+
+
+def test_to_json_schema__lenient_uuid():
+    schema = get_json_schema(describe_type(Annotated[UUID, LenientUUID()]))
+
+    assert schema == {
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "anyOf": [
+            {"type": "string", "format": "uuid"},
+            {"type": "integer", "minimum": 0},
+        ],
+        "definitions": {},
+    }
+
+
+def test_to_json_schema__pydantic_model():
+    pydantic = pytest.importorskip("pydantic")
+
+    class Tag(pydantic.BaseModel):
+        name: str
+
+    class Point(pydantic.BaseModel):
+        x: int
+        tags: list[Tag]
+
+    schema = get_json_schema(describe_type(list[Point]))
+    assert schema["items"] == {"$ref": "#/definitions/Point"}
+    assert schema["definitions"]["Point"]["properties"]["tags"]["items"] == {"$ref": "#/definitions/Tag"}
+    assert schema["definitions"]["Tag"]["required"] == ["name"]
@@ -1,17 +1,34 @@
 import sys
 import uuid
 from dataclasses import dataclass
-from datetime import date, datetime, time
+from datetime import date, datetime, time, timedelta
 from decimal import Decimal
 from enum import Enum
+from ipaddress import IPv4Address, IPv4Network, IPv6Address
 from typing import Annotated, Any, Optional
 from unittest import mock
 
 import pytest
+from serpyco_rs import SecretStr
 from serpyco_rs._describe import describe_type
 from serpyco_rs._json_schema import JsonschemaRSValidator, get_json_schema
 from serpyco_rs.exceptions import ErrorItem, SchemaValidationError
-from serpyco_rs.metadata import Max, MaxLength, Min, MinLength
+from serpyco_rs.metadata import (
+    Base64,
+    DateTimeFormats,
+    ExclusiveMax,
+    ExclusiveMin,
+    Hex,
+    LenientDate,
+    LenientUUID,
+    Max,
+    MaxLength,
+    Min,
+    MinLength,
+    MultipleOf,
+    SecondsDuration,
+    UniqueItems,
+)
 
 
 class EnumTest(Enum):
@@ -33,13 +50,19 @@ class EntityTest:
         (Annotated[str, MinLength(1), MaxLength(3)], "12"),
         (int, -99),
         (Annotated[int, Min(1), Max(1000)], 99),
+        (Annotated[int, MultipleOf(3)], 9),
+        (Annotated[int, ExclusiveMin(0), ExclusiveMax(2)], 1),
         # (bytes, b'xx'),  # todo: fix bytes validation
+        (Annotated[bytes, Base64], "Zm8="),
+        (Annotated[bytes, Hex], "00ff"),
         (float, 1.3),
         (Annotated[float, Min(0), Max(0.4)], 0.1),
         (Decimal, "0.1"),  # support str
         (Decimal, 0.1),  # or int input
         (Decimal, "NaN"),  # or int input
         (uuid.UUID, str(uuid.uuid4())),  # support only str input
+        (Annotated[uuid.UUID, LenientUUID()], str(uuid.uuid4())),
+        (Annotated[uuid.UUID, LenientUUID()], 1234567890),
         (time, "12:34"),
         (time, "12:34Z"),
         (time, "12:34:56"),
@@ -54,7 +77,21 @@ class EntityTest:
         (datetime, "2022-10-10T14:23:43.123456Z"),
         (datetime, "2022-10-10T14:23:43.123456+00:00"),
         (datetime, "2022-10-10T14:23:43.123456-30:00"),
+        (Annotated[datetime, DateTimeFormats(("iso", "epoch"))], "2022-10-10T14:23:43"),
+        (Annotated[datetime, DateTimeFormats(("iso", "epoch"))], 1665411823),
+        (Annotated[datetime, DateTimeFormats(("%d/%m/%Y",))], "10/10/2022"),
         (date, "2020-07-17"),
+        (Annotated[date, LenientDate()], "2020-07-17"),
+        (Annotated[date, LenientDate()], "2020-W29-5"),
+        (Annotated[date, LenientDate()], "2020-199"),
+        (timedelta, "PT0S"),
+        (timedelta, "P1W2DT3H4M5.5S"),
+        (timedelta, "-P1D"),
+        (timedelta, "+PT1S"),
+        (Annotated[timedelta, SecondsDuration], 1.5),
+        (IPv4Address, "192.168.0.1"),
+        (IPv6Address, "2001:db8::1"),
+        (IPv4Network, "10.0.0.0/8"),
         (EnumTest, "foo"),
         (Optional[int], None),
         (Optional[int], 1),
@@ -62,6 +99,9 @@ class EntityTest:
         (list[int], [1, 2]),
         (dict[str, int], {"a": 1}),
         (tuple[str, int, bool], ["1", 2, True]),
+        (Annotated[list[int], UniqueItems()], [1, 2]),
+        (tuple[int, ...], []),
+        (tuple[int, ...], [1, 2, 3]),
         (Any, ["1", 2, True]),
         (Any, {}),
     ),
@@ -117,6 +157,26 @@ def _mk_e(m=mock.ANY, ip=mock.ANY, sp=mock.ANY) -> ErrorItem:
             10,
             _mk_e(m="10 is greater than the maximum of 1", sp="maximum"),
         ),
+        (
+            Annotated[int, MultipleOf(3)],
+            7,
+            _mk_e(m="7 is not a multiple of 3", sp="multipleOf"),
+        ),
+        (
+            Annotated[list[int], UniqueItems()],
+            [1, 2, 1],
+            _mk_e(sp="uniqueItems"),
+        ),
+        (
+            Annotated[int, ExclusiveMin(1)],
+            1,
+            _mk_e(m="1 is less than or equal to the minimum of 1", sp="exclusiveMinimum"),
+        ),
+        (
+            Annotated[float, ExclusiveMax(1)],
+            1.0,
+            _mk_e(m="1.0 is greater than or equal to the maximum of 1", sp="exclusiveMaximum"),
+        ),
         (float, None, _mk_e(m='null is not of type "number"')),
         (
             Annotated[float, Min(1)],
@@ -198,3 +258,64 @@ def test_validate__error_format():
             schema_path="properties/foo/type",
         ),
     ]
+
+
+def test_validate__big_int_bound__error_format():
+    @dataclass
+    class A:
+        foo: Annotated[int, Max(2**70)]
+
+    v = JsonschemaRSValidator(get_json_schema(describe_type(A)))
+    v.validate({"foo": 2**70})
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        v.validate({"foo": 2**70 + 1})
+
+    assert exc_info.value.errors == [
+        ErrorItem(
+            message=f"{2**70 + 1} is greater than the maximum of {2**70}",
+            instance_path="foo",
+            schema_path="properties/foo/maximum",
+        ),
+    ]
+
+
+def test_validate__error_value_and_expected():
+    @dataclass
+    class Inner:
+        baz: str
+
+    @dataclass
+    class A:
+        foo: int
+        bar: Inner
+        qux: Optional[int]
+
+    v = JsonschemaRSValidator(get_json_schema(describe_type(A)))
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        v.validate({"foo": "x" * 200, "bar": {"baz": 1}, "qux": "x"})
+
+    errors = {e.instance_path: e for e in exc_info.value.errors}
+    assert errors["foo"].expected == "integer"
+    assert errors["foo"].value.startswith("'xxx") and len(errors["foo"].value) < 100
+    assert errors["bar/baz"].expected == "string"
+    assert errors["bar/baz"].value == "1"
+    assert sorted(errors["qux"].expected) == ["integer", "null"]
+
+
+def test_validate__missing_field_next_to_secret__value_not_shown():
+    @dataclass
+    class Credentials:
+        user: str
+        password: SecretStr
+
+    v = JsonschemaRSValidator(get_json_schema(describe_type(Credentials)))
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        v.validate({"password": "s3cr3t"})
+
+    [error] = exc_info.value.errors
+    assert error.schema_path == "required"
+    assert error.value is None
+    assert "s3cr3t" not in str(exc_info.value)
@@ -0,0 +1,232 @@
+from dataclasses import dataclass
+from datetime import date, datetime
+from decimal import Decimal
+from enum import Enum
+from typing import Annotated, Optional
+
+import pytest
+from serpyco_rs import SchemaValidationError, Serializer
+from serpyco_rs._constraints import check_constraints
+from serpyco_rs._describe import describe_type
+from serpyco_rs.exceptions import ErrorItem
+from serpyco_rs.metadata import (
+    DateTimeFormats,
+    EnumDumpFormat,
+    ExclusiveMax,
+    ExclusiveMin,
+    ExtraKeys,
+    Max,
+    MaxDigits,
+    MaxLength,
+    Min,
+    MinLength,
+    MultipleOf,
+    Places,
+    SkipIfDefault,
+    SortedBy,
+    StringFormat,
+    ToLower,
+    ToUpper,
+    UniqueBy,
+)
+
+
+@pytest.mark.parametrize(
+    "t",
+    (
+        int,
+        Annotated[int, Min(1), Max(1)],
+        Annotated[float, Min(0.1), Max(0.2)],
+        Annotated[Decimal, Min(Decimal("0.1")), Max(Decimal("0.2"))],
+        Annotated[str, MinLength(0), MaxLength(0)],
+    ),
+)
+def test_check_constraints__consistent__ok(t):
+    check_constraints(describe_type(t))
+
+
+@pytest.mark.parametrize(
+    ["t", "err"],
+    (
+        (
+            Annotated[int, Min(10), Max(1)],
+            ErrorItem(message="min (10) is greater than max (1)", instance_path="", schema_path="minimum"),
+        ),
+        (
+            Annotated[float, Min(1.5), Max(0.5)],
+            ErrorItem(message="min (1.5) is greater than max (0.5)", instance_path="", schema_path="minimum"),
+        ),
+        (
+            Annotated[str, MinLength(5), MaxLength(2)],
+            ErrorItem(
+                message="min_length (5) is greater than max_length (2)", instance_path="", schema_path="minLength"
+            ),
+        ),
+        (
+            Annotated[int, Min(1), ExclusiveMax(1)],
+            ErrorItem(message="min (1) must be less than exclusive_max (1)", instance_path="", schema_path="minimum"),
+        ),
+        (
+            Annotated[float, ExclusiveMin(2.0), Max(1.0)],
+            ErrorItem(
+                message="exclusive_min (2.0) must be less than max (1.0)",
+                instance_path="",
+                schema_path="exclusiveMinimum",
+            ),
+        ),
+        (
+            Annotated[int, MultipleOf(0)],
+            ErrorItem(message="multiple_of (0) must be greater than 0", instance_path="", schema_path="multipleOf"),
+        ),
+        (
+            Annotated[Decimal, MultipleOf(Decimal("-0.01"))],
+            ErrorItem(message="multiple_of (-0.01) must be greater than 0", instance_path="", schema_path="multipleOf"),
+        ),
+        (
+            Annotated[Decimal, MaxDigits(2), Places(3)],
+            ErrorItem(message="places (3) is greater than max_digits (2)", instance_path="", schema_path="places"),
+        ),
+        (
+            Annotated[str, MaxLength(-1)],
+            ErrorItem(message="max_length (-1) must be non-negative", instance_path="", schema_path="maxLength"),
+        ),
+        (
+            Annotated[tuple[int, ...], MinLength(2), MaxLength(1)],
+            ErrorItem(
+                message="min_length (2) is greater than max_length (1)", instance_path="", schema_path="minItems"
+            ),
+        ),
+        (
+            Annotated[date, Min(date(2020, 1, 2)), Max(date(2020, 1, 1))],
+            ErrorItem(
+                message="min (2020-01-02) is greater than max (2020-01-01)", instance_path="", schema_path="minimum"
+            ),
+        ),
+        (
+            Annotated[datetime, DateTimeFormats(())],
+            ErrorItem(message="formats must not be empty", instance_path="", schema_path="formats"),
+        ),
+        (
+            Annotated[datetime, DateTimeFormats(("iso", "rfc3339"))],
+            ErrorItem(
+                message=(
+                    "format 'rfc3339' is not 'iso', 'iso_seconds', 'epoch', 'epoch_ms', 'rfc2822' or a strftime pattern"
+                ),
+                instance_path="",
+                schema_path="formats",
+            ),
+        ),
+        (
+            Annotated[str, StringFormat("semver"), Min("2.0.0"), Max("2.0.0-rc.1")],
+            ErrorItem(message="min (2.0.0) is greater than max (2.0.0-rc.1)", instance_path="", schema_path="minimum"),
+        ),
+        (
+            Annotated[str, StringFormat("semver"), Min("v1")],
+            ErrorItem(message="min ('v1') is not a valid semantic version", instance_path="", schema_path="minimum"),
+        ),
+        (
+            Annotated[str, ToLower(), ToUpper()],
+            ErrorItem(message="to_lower and to_upper are mutually exclusive", instance_path="", schema_path="toUpper"),
+        ),
+        (
+            Annotated[str, StringFormat("email")],
+            ErrorItem(message="format 'email' is not supported", instance_path="", schema_path="format"),
+        ),
+    ),
+)
+def test_check_constraints__contradictory__error(t, err):
+    with pytest.raises(SchemaValidationError) as exc_info:
+        check_constraints(describe_type(t))
+
+    assert exc_info.value.errors == [err]
+
+
+def test_check_constraints__unique_by_unknown_field__error():
+    @dataclass
+    class Item:
+        id: int
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(Annotated[list[Item], UniqueBy("uid")])
+
+    assert exc_info.value.errors == [
+        ErrorItem(message="Item has no field 'uid'", instance_path="", schema_path="uniqueBy")
+    ]
+
+
+def test_check_constraints__sorted_by__error():
+    @dataclass
+    class Item:
+        id: int
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(Annotated[list[Item], SortedBy("uid", "up")])
+
+    assert exc_info.value.errors == [
+        ErrorItem(message="order 'up' is not 'asc' or 'desc'", instance_path="", schema_path="sortedBy"),
+        ErrorItem(message="Item has no field 'uid'", instance_path="", schema_path="sortedBy"),
+    ]
+
+
+def test_check_constraints__enum_dump_format__error():
+    class Color(Enum):
+        RED = "red"
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(Annotated[Color, EnumDumpFormat("label")])
+
+    assert exc_info.value.errors == [
+        ErrorItem(
+            message="dump format 'label' is not 'str', 'value' or 'name'", instance_path="", schema_path="dumpFormat"
+        ),
+    ]
+
+
+def test_check_constraints__skip_if_default_without_default__error():
+    @dataclass
+    class Item:
+        id: Annotated[int, SkipIfDefault()]
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(Item)
+
+    assert exc_info.value.errors == [
+        ErrorItem(
+            message="skip_if_default requires a default value of 'id'", instance_path="id", schema_path="skipIfDefault"
+        )
+    ]
+
+
+def test_check_constraints__extra_keys_not_dict__error():
+    @dataclass
+    class Item:
+        id: int
+        extra: Annotated[list[str], ExtraKeys()]
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(Item)
+
+    assert exc_info.value.errors == [
+        ErrorItem(
+            message="ExtraKeys field 'extra' is not a dict with str keys",
+            instance_path="extra",
+            schema_path="additionalProperties",
+        )
+    ]
+
+
+def test_check_constraints__nested_field__error_has_path():
+    @dataclass
+    class Inner:
+        value: Optional[list[Annotated[int, Min(2), Max(1)]]]
+
+    @dataclass
+    class Outer:
+        inner: Inner
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(Outer)
+
+    assert exc_info.value.errors == [
+        ErrorItem(message="min (2) is greater than max (1)", instance_path="inner/value", schema_path="minimum"),
+    ]
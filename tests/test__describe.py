@@ -1,10 +1,12 @@
 import sys
 from collections.abc import Mapping
 from dataclasses import dataclass, field
-from datetime import date, datetime, time
+from datetime import date, datetime, time, timedelta
 from decimal import Decimal
 from enum import Enum
-from typing import Annotated, Any, Generic, Optional, Sequence, TypeVar, Union
+from ipaddress import IPv4Address, IPv4Interface, IPv6Network
+from pathlib import Path, PurePosixPath
+from typing import Annotated, Any, FrozenSet, Generic, Optional, Sequence, Set, TypeVar, Union
 from unittest import mock
 from unittest.mock import ANY
 from uuid import UUID
@@ -16,6 +18,7 @@ from serpyco_rs._describe import (
     AnyType,
     ArrayType,
     BooleanType,
+    BytesType,
     DateTimeType,
     DateType,
     DecimalType,
@@ -25,14 +28,47 @@ from serpyco_rs._describe import (
     EnumType,
     FloatType,
     IntegerType,
+    IPAddressType,
+    IPInterfaceType,
+    IPNetworkType,
+    JsonStringType,
     OptionalType,
+    PathType,
+    SetType,
     StringType,
+    TimeDurationType,
     TimeType,
     TupleType,
     UUIDType,
     describe_type,
 )
-from serpyco_rs.metadata import CamelCase, Max, MaxLength, Min, MinLength, NoFormat, Places
+from serpyco_rs.metadata import (
+    AbsolutePath,
+    Base64,
+    BytesEncoding,
+    CamelCase,
+    CanonicalUUID,
+    ClampYear,
+    DateTimeFormats,
+    DurationFormat,
+    Examples,
+    ExclusiveMax,
+    ExclusiveMin,
+    JsonString,
+    LenientDate,
+    Max,
+    MaxDigits,
+    MaxLength,
+    Min,
+    MinLength,
+    MultipleOf,
+    NoFormat,
+    NoParentRefs,
+    Places,
+    SecondsDuration,
+    UniqueItems,
+    ZSuffix,
+)
 
 T = TypeVar("T")
 U = TypeVar("U")
@@ -463,6 +499,7 @@ def test_describe__attrs_and_annotated_with_min_max__parsed():
                 name="x",
                 dict_key="x",
                 type=IntegerType(min=10, max=20),
+                slot=SomeEntity.x,
             )
         ],
         name=ANY,
@@ -484,11 +521,25 @@ def test_describe__attrs_and_annotated_with_min_max_length__parsed():
                 name="x",
                 dict_key="x",
                 type=StringType(min_length=10, max_length=20),
+                slot=SomeEntity.x,
             )
         ],
     )
 
 
+def test_describe__attrs_private_field_and_metadata__parsed():
+    @attr.define
+    class SomeEntity:
+        _x: int = attr.field(metadata={"serpyco_rs": [Min(0)]})
+        y: int = attr.field(alias="why")
+
+    fields = describe_type(SomeEntity).fields
+
+    assert fields[0].type == IntegerType(min=0)
+    assert fields[0].init_name == "x"
+    assert fields[1].init_name == "why"
+
+
 def test_describe__type_with_typevar__fail():
     with pytest.raises(RuntimeError) as exc_info:
         describe_type(list[T])
@@ -498,9 +549,9 @@ def test_describe__type_with_typevar__fail():
 
 def test_describe__unknown_type__fail():
     with pytest.raises(RuntimeError) as exc_info:
-        describe_type(set[int])
+        describe_type(complex)
 
-    assert exc_info.match("Unknown type <class 'set'>")
+    assert exc_info.match("Unknown type <class 'complex'>")
 
 
 def test_describe__optional__wrapped():
@@ -519,11 +570,33 @@ def test_describe__new_style_union_type__wrapped():
     assert describe_type(int | None) == OptionalType(IntegerType())
 
 
+@pytest.mark.parametrize(
+    ["t", "expected"],
+    (
+        (set[int], SetType(item_type=IntegerType(), is_frozen=False)),
+        (Set[str], SetType(item_type=StringType(), is_frozen=False)),
+        (frozenset[int], SetType(item_type=IntegerType(), is_frozen=True)),
+        (FrozenSet[int], SetType(item_type=IntegerType(), is_frozen=True)),
+        (set, SetType(item_type=AnyType(), is_frozen=False)),
+        (Annotated[set[int], UniqueItems()], SetType(item_type=IntegerType(), is_frozen=False, unique_items=True)),
+    ),
+)
+def test_describe__set__parsed(t, expected):
+    assert describe_type(t) == expected
+
+
 def test_describe__tuple__parsed():
     assert describe_type(tuple[int, str]) == TupleType([IntegerType(), StringType()])
 
 
-@pytest.mark.parametrize("t", [tuple, tuple[int, ...]])
+def test_describe__variadic_tuple__parsed():
+    assert describe_type(tuple[int, ...]) == TupleType([IntegerType()], variadic=True)
+    assert describe_type(Annotated[tuple[str, ...], MinLength(1), MaxLength(3)]) == TupleType(
+        [StringType()], variadic=True, min_length=1, max_length=3
+    )
+
+
+@pytest.mark.parametrize("t", [tuple, tuple[int, str, ...]])
 def test_describe__invalid_tuple__error(t):
     with pytest.raises(RuntimeError) as exc_info:
         describe_type(t)
@@ -531,10 +604,96 @@ def test_describe__invalid_tuple__error(t):
     assert exc_info.match("Variable length tuples are not supported")
 
 
+def test_describe__bytes_with_format__parsed():
+    assert describe_type(bytes) == BytesType(encoding=BytesEncoding.raw)
+    assert describe_type(Annotated[bytes, Base64]) == BytesType(encoding=BytesEncoding.base64)
+
+
+def test_describe__lenient_date__parsed():
+    assert describe_type(date) == DateType(lenient=False)
+    assert describe_type(Annotated[date, LenientDate()]) == DateType(lenient=True)
+
+
+def test_describe__clamp_year__parsed():
+    assert describe_type(Annotated[date, ClampYear()]) == DateType(clamp_year=True)
+    assert describe_type(Annotated[datetime, ClampYear()]) == DateTimeType(clamp_year=True)
+
+
+def test_describe__ip__parsed():
+    assert describe_type(IPv4Address) == IPAddressType(cls=IPv4Address, version=4)
+    assert describe_type(IPv6Network) == IPNetworkType(cls=IPv6Network, version=6)
+    assert describe_type(IPv4Interface) == IPInterfaceType(cls=IPv4Interface, version=4)
+
+
+def test_describe__datetime_formats__parsed():
+    assert describe_type(datetime) == DateTimeType(formats=("iso",))
+    assert describe_type(Annotated[datetime, DateTimeFormats(("epoch", "%Y"))]) == DateTimeType(formats=("epoch", "%Y"))
+
+
+def test_describe__unique_items__parsed():
+    assert describe_type(Annotated[list[int], UniqueItems()]) == ArrayType(
+        item_type=IntegerType(), is_sequence=False, unique_items=True
+    )
+
+
+def test_describe__json_string__parsed():
+    assert describe_type(Annotated[list[int], JsonString()]) == JsonStringType(
+        inner=ArrayType(item_type=IntegerType(), is_sequence=False)
+    )
+    assert describe_type(Annotated[str, JsonString(), MaxLength(3)]) == JsonStringType(
+        inner=StringType(max_length=3)
+    )
+
+
+def test_describe__path__parsed():
+    assert describe_type(Path) == PathType(cls=Path, absolute=False, allow_parent_refs=True)
+    assert describe_type(Annotated[PurePosixPath, AbsolutePath(), NoParentRefs()]) == PathType(
+        cls=PurePosixPath, absolute=True, allow_parent_refs=False
+    )
+
+
+def test_describe__datetime_z_suffix__parsed():
+    assert describe_type(Annotated[datetime, ZSuffix()]) == DateTimeType(z_suffix=True)
+
+
+def test_describe__timedelta__parsed():
+    assert describe_type(timedelta) == TimeDurationType(format=DurationFormat.iso8601)
+    assert describe_type(Annotated[timedelta, SecondsDuration]) == TimeDurationType(format=DurationFormat.seconds)
+
+
 def test_describe__decimal_with_places__parsed():
     assert describe_type(Annotated[Decimal, Places(3)]) == DecimalType(places=3)
 
 
+def test_describe__decimal_with_max_digits__parsed():
+    assert describe_type(Annotated[Decimal, MaxDigits(10), Places(2)]) == DecimalType(max_digits=10, places=2)
+
+
+@pytest.mark.parametrize(
+    ["t", "expected"],
+    (
+        (Annotated[int, MultipleOf(3)], IntegerType(multiple_of=3)),
+        (Annotated[float, MultipleOf(0.5)], FloatType(multiple_of=0.5)),
+        (Annotated[Decimal, MultipleOf(Decimal("0.01"))], DecimalType(multiple_of=Decimal("0.01"))),
+        (Annotated[Decimal, MultipleOf(0.01)], DecimalType(multiple_of=Decimal("0.01"))),
+    ),
+)
+def test_describe__number_with_multiple_of__parsed(t, expected):
+    assert describe_type(t) == expected
+
+
+@pytest.mark.parametrize(
+    ["t", "expected"],
+    (
+        (Annotated[int, ExclusiveMin(0), ExclusiveMax(10)], IntegerType(exclusive_min=0, exclusive_max=10)),
+        (Annotated[float, ExclusiveMin(0.5)], FloatType(exclusive_min=0.5)),
+        (Annotated[Decimal, ExclusiveMax(Decimal(1))], DecimalType(exclusive_max=Decimal(1))),
+    ),
+)
+def test_describe__number_with_exclusive_bounds__parsed(t, expected):
+    assert describe_type(t) == expected
+
+
 def test_describe__dataclass_field_format__parsed():
     @dataclass
     class InnerEntity:
@@ -582,3 +741,38 @@ def test_describe__dataclass_field_format__parsed():
         ],
         doc=mock.ANY,
     )
+
+
+def test_describe__field_examples__parsed():
+    @dataclass
+    class Entity:
+        x: Annotated[int, Examples((1, 2))]
+
+    [field] = describe_type(Entity).fields
+    assert field == EntityField(name="x", dict_key="x", type=IntegerType())
+    assert field.type.examples == [1, 2]
+
+
+def test_describe__examples__entity_type_not_shared():
+    @dataclass
+    class Item:
+        x: int
+
+    @dataclass
+    class Entity:
+        a: Annotated[Item, Examples((Item(1),))]
+        b: Item
+
+    entity = describe_type(Entity)
+    assert entity.fields[0].type.examples == [Item(1)]
+    assert entity.fields[1].type.examples is None
+
+
+def test_describe__canonical_uuid_str__parsed():
+    assert describe_type(Annotated[str, CanonicalUUID()]) == UUIDType(as_str=True)
+
+
+def test_describe__date_bounds__parsed():
+    assert describe_type(Annotated[date, Min(date(2000, 1, 1)), Max(date(2020, 1, 1))]) == DateType(
+        min=date(2000, 1, 1), max=date(2020, 1, 1)
+    )
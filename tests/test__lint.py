@@ -0,0 +1,30 @@
+from dataclasses import dataclass, field
+from typing import Optional
+
+from serpyco_rs import Serializer
+from serpyco_rs._lint import LintWarning
+
+
+def test_lint__no_issues__empty():
+    @dataclass
+    class A:
+        foo: int
+        bar: Optional[int] = None
+        baz: Optional[list[int]] = field(default_factory=list)
+
+    assert Serializer(A).lint() == []
+
+
+def test_lint__optional_field_without_default__warning():
+    @dataclass
+    class Inner:
+        value: Optional[str]
+
+    @dataclass
+    class Outer:
+        items: list[Inner]
+        inner: Optional[Inner] = None
+
+    assert Serializer(Outer).lint() == [
+        LintWarning(message="Optional field Inner.value without default", instance_path="items/value"),
+    ]
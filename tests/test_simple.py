@@ -1,11 +1,29 @@
+import copy
+import gc
+import json
+import pickle
 import sys
+import weakref
 from collections.abc import Mapping, Sequence
 from dataclasses import dataclass, field
-from typing import Annotated, List, Optional
+from datetime import date, datetime, timezone
+from decimal import Decimal
+from enum import Enum
+from typing import Annotated, Any, List, Optional
+from uuid import UUID
 
 import pytest
-from serpyco_rs import SchemaValidationError, Serializer
-from serpyco_rs.metadata import CamelCase, NoFormat
+from serpyco_rs import (
+    JsonschemaRSValidator,
+    SchemaValidationError,
+    Serializer,
+    ValidationError,
+    Validator,
+    configure,
+    get_or_create_serializer,
+)
+from serpyco_rs.exceptions import ErrorItem
+from serpyco_rs.metadata import CamelCase, ErrorMessage, MaxLength, Min, NoFormat
 
 
 def test_dump_simple_fields_types():
@@ -230,3 +248,456 @@ def test_serializer_with_camelcase():
 
     assert serializer.load(expected) == obj
     assert serializer.dump(obj) == expected
+
+
+def test_get_or_create_serializer__cached_per_options():
+    @dataclass
+    class A:
+        foo_bar: int
+
+    serializer = get_or_create_serializer(A)
+    camelcase_serializer = get_or_create_serializer(A, camelcase_fields=True)
+
+    assert get_or_create_serializer(A) is serializer
+    assert get_or_create_serializer(A, camelcase_fields=True) is camelcase_serializer
+    assert camelcase_serializer is not serializer
+    assert camelcase_serializer.dump(A(foo_bar=1)) == {"fooBar": 1}
+
+
+def test_get_or_create_serializer__cache_does_not_keep_type_alive():
+    @dataclass
+    class A:
+        foo_bar: int
+
+    get_or_create_serializer(A)
+    ref = weakref.ref(A)
+    del A
+    gc.collect()
+
+    assert ref() is None
+
+
+def test_get_or_create_serializer__builtin_type__cached():
+    assert get_or_create_serializer(int) is get_or_create_serializer(int)
+
+
+def test_get_or_create_serializer__unhashable_options():
+    @dataclass
+    class A:
+        name: str
+
+    serializer = get_or_create_serializer(A, error_messages={"type": "Wrong type"})
+
+    assert get_or_create_serializer(A, error_messages={"type": "Wrong type"}) is serializer
+    with pytest.raises(TypeError, match="get_or_create_serializer option error_messages=.* is not hashable"):
+        get_or_create_serializer(A, error_messages={"type": bytearray(b"Wrong type")})
+
+
+def test_configure__defaults_used_by_serializers():
+    @dataclass
+    class A:
+        foo_bar: int
+
+    cached = get_or_create_serializer(A)
+    configure(camelcase_fields=True)
+    try:
+        assert Serializer(A).dump(A(foo_bar=1)) == {"fooBar": 1}
+        assert Serializer(A, camelcase_fields=False).dump(A(foo_bar=1)) == {"foo_bar": 1}
+        assert get_or_create_serializer(A) is not cached
+        assert get_or_create_serializer(A).dump(A(foo_bar=1)) == {"fooBar": 1}
+    finally:
+        configure(camelcase_fields=False)
+
+    with pytest.raises(TypeError, match="Unknown Serializer options: omit_none"):
+        configure(omit_none=True)
+
+
+def test_configure__none_overrides_default():
+    configure(assume_timezone=timezone.utc)
+    try:
+        assert Serializer(datetime).load("2024-01-02T03:04:05") == datetime(2024, 1, 2, 3, 4, 5, tzinfo=timezone.utc)
+        assert Serializer(datetime, assume_timezone=None).load("2024-01-02T03:04:05") == datetime(2024, 1, 2, 3, 4, 5)
+    finally:
+        configure(assume_timezone=None)
+
+
+def test_error_messages__templates_by_keyword():
+    @dataclass
+    class A:
+        name: str
+        age: Annotated[int, ErrorMessage("Возраст указан неверно")]
+
+    messages = {"required": "Поле обязательно: {message}", "type": "Ожидается {expected}, получено {value}"}
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(A, error_messages=messages).load({"age": "x"})
+    assert [e.message for e in exc_info.value.errors] == [
+        'Поле обязательно: "name" is a required property',
+        "Возраст указан неверно",
+    ]
+
+    configure(error_messages=messages)
+    try:
+        with pytest.raises(SchemaValidationError) as exc_info:
+            Serializer(list[int]).load([1, "2"])
+        assert exc_info.value.errors[0].message == "Ожидается integer, получено '2'"
+    finally:
+        configure(error_messages=None)
+
+
+def test_error_messages__custom_validator_cls():
+    class StrictValidator(Validator):
+        def __init__(self, schema: dict[str, Any]) -> None:
+            self._validator = JsonschemaRSValidator(schema)
+
+        def validate(self, data: Any) -> None:
+            self._validator.validate(data)
+
+    configure(error_messages={"type": "Wrong type: {value}"})
+    try:
+        serializer = Serializer(list[int], validator_cls=StrictValidator)
+        with pytest.raises(SchemaValidationError) as exc_info:
+            serializer.load([1, "2"])
+        assert exc_info.value.errors[0].message == "Wrong type: '2'"
+    finally:
+        configure(error_messages=None)
+
+
+@pytest.mark.parametrize("template", ("Stray { brace", "Unknown {placeholder}", "Positional {0}"))
+def test_error_messages__invalid_template__keep_message(template):
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(list[int], error_messages={"type": template}).load([1, "2"])
+    assert exc_info.value.errors[0].message == '"2" is not of type "integer"'
+
+
+def test_schema_validation_error__by_path():
+    @dataclass
+    class A:
+        name: Annotated[str, MaxLength(2)]
+        tags: list[str]
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(A).load({"name": "abc", "tags": [1, "a", 2]})
+
+    assert {path: [e.schema_path for e in errors] for path, errors in exc_info.value.by_path().items()} == {
+        "name": ["properties/name/maxLength"],
+        "tags/0": ["properties/tags/items/type"],
+        "tags/2": ["properties/tags/items/type"],
+    }
+
+
+@pytest.mark.parametrize("copier", (lambda e: pickle.loads(pickle.dumps(e)), copy.deepcopy))
+def test_errors__pickle_and_deepcopy(copier):
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(list[int]).load([1, "2"])
+    error = copier(exc_info.value)
+    assert type(error) is SchemaValidationError
+    assert error.errors == exc_info.value.errors
+    assert error.errors[0].path == (1,)
+    assert error.errors[0].value == "'2'"
+
+    with pytest.raises(ValidationError) as exc_info:
+        Serializer(Decimal).load("abc", validate=False)
+    error = copier(exc_info.value)
+    assert type(error) is type(exc_info.value)
+    assert error.args == exc_info.value.args
+
+
+def test_serializer__validate_returns_errors():
+    @dataclass
+    class A:
+        name: Annotated[str, MaxLength(2)]
+        price: Decimal
+
+    serializer = Serializer(A)
+    assert serializer.validate({"name": "ab", "price": "1.5"}) == []
+    assert [e.instance_path for e in serializer.validate({"name": "abc", "price": 1})] == ["name"]
+    [error] = serializer.validate({"name": "ab", "price": "abc"})
+    assert error.message.startswith("invalid Decimal value")
+
+
+@dataclass
+class Dated:
+    d: Annotated[date, Min(date(2020, 1, 1))]
+
+
+def test_serializer__validate__encoder_error_path():
+    @dataclass
+    class Batch:
+        items: list[Dated]
+
+    [error] = Serializer(Batch).validate({"items": [{"d": "2021-01-01"}, {"d": "2019-01-01"}]})
+
+    assert (error.instance_path, error.schema_path, error.path) == ("items/1/d", "", ("items", 1, "d"))
+
+
+def test_load_batch__collect__encoder_error_path():
+    _, [(index, error)] = Serializer(list[Dated]).load_batch([{"d": "2019-01-01"}], on_error="collect")
+
+    assert (index, error.instance_path, error.path) == (0, "0/d", (0, "d"))
+
+
+def test_validate_field__encoder_error_path():
+    @dataclass
+    class Holder:
+        dates: list[Dated]
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(Holder).validate_field("dates", [{"d": "2019-01-01"}])
+
+    assert exc_info.value.errors[0].path == ("dates", 0, "d")
+
+
+def test_get_or_create_serializer__annotated_type__works():
+    serializer = get_or_create_serializer(Annotated[int, NoFormat])
+
+    assert serializer.load(1) == 1
+
+
+def test_load_with_report__ignored_keys_and_defaults_reported():
+    @dataclass
+    class Item:
+        name: str
+        count: int = 1
+
+    @dataclass
+    class Order:
+        items: list[Item]
+        tags: dict[str, Item]
+        comment: Optional[str] = None
+
+    serializer = Serializer(Order)
+
+    value, report = serializer.load_with_report(
+        {
+            "items": [{"name": "a", "count": 2}, {"name": "b", "color": "red"}],
+            "tags": {"x": {"name": "c"}},
+            "extra": True,
+        }
+    )
+
+    assert value == Order(items=[Item("a", 2), Item("b")], tags={"x": Item("c")})
+    assert report.ignored_keys == ["extra", "items/1/color"]
+    assert report.defaulted_fields == ["items/1/count", "tags/x/count", "comment"]
+
+
+def test_iter_load_json__yields_items():
+    @dataclass
+    class Item:
+        name: str
+        count: int = 1
+
+    serializer = Serializer(list[Item])
+    items = serializer.iter_load_json(b' [{"name": "a", "count": 2},\n {"name": "b"}] ')
+
+    assert next(items) == Item("a", 2)
+    assert list(items) == [Item("b")]
+    assert list(serializer.iter_load_json("[]")) == []
+
+
+def test_iter_load_json__invalid_item__error_has_index():
+    @dataclass
+    class Item:
+        name: str
+
+    serializer = Serializer(list[Item])
+    items = serializer.iter_load_json('[{"name": "a"}, {"name": 1}]')
+
+    assert next(items) == Item("a")
+    with pytest.raises(SchemaValidationError) as exc_info:
+        next(items)
+    assert exc_info.value.errors == [
+        ErrorItem(message='1 is not of type "string"', instance_path="1/name", schema_path="properties/name/type")
+    ]
+
+
+@pytest.mark.parametrize("data", ("[1,]", "[1 2]", "[1] 2", "{}"))
+def test_iter_load_json__malformed__raise_decode_error(data):
+    with pytest.raises(json.JSONDecodeError):
+        list(Serializer(list[int]).iter_load_json(data))
+
+
+def test_iter_load_json__not_list__raise_type_error():
+    with pytest.raises(TypeError):
+        next(Serializer(int).iter_load_json("[1]"))
+
+
+@dataclass
+class BatchItem:
+    name: str
+    count: int = 1
+
+
+_batch = [{"name": "a"}, {"name": 1}, {"name": "c", "count": 3}, {}]
+
+
+def test_load_batch__raise__error_has_index():
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(list[BatchItem]).load_batch(_batch)
+
+    assert exc_info.value.errors == [
+        ErrorItem(message='1 is not of type "string"', instance_path="1/name", schema_path="properties/name/type")
+    ]
+
+
+def test_error_item_path__keys_and_indexes():
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(dict[str, list[BatchItem]]).load({"1": [{"name": "a"}, {"name": 1}]})
+    assert exc_info.value.errors[0].instance_path == "1/1/name"
+    assert exc_info.value.errors[0].path == ("1", 1, "name")
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(list[BatchItem]).load_batch(_batch)
+    assert exc_info.value.errors[0].path == (1, "name")
+
+
+def test_load_batch__skip__keeps_valid_items():
+    assert Serializer(list[BatchItem]).load_batch(_batch, on_error="skip") == [BatchItem("a"), BatchItem("c", 3)]
+
+
+def test_load_batch__collect__returns_errors():
+    items, errors = Serializer(list[BatchItem]).load_batch(_batch, on_error="collect")
+
+    assert items == [BatchItem("a"), BatchItem("c", 3)]
+    assert errors == [
+        (1, ErrorItem(message='1 is not of type "string"', instance_path="1/name", schema_path="properties/name/type")),
+        (3, ErrorItem(message='"name" is a required property', instance_path="3", schema_path="required")),
+    ]
+
+
+def test_max_errors__caps_reported_errors():
+    serializer = Serializer(list[BatchItem], max_errors=2)
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load([{"name": i} for i in range(1000)])
+    assert len(exc_info.value.errors) == 2
+
+    _, errors = serializer.load_batch([{}] * 5, on_error="collect")
+    assert [index for index, _ in errors] == [0, 1]
+
+
+@pytest.mark.parametrize("max_errors", (0, -1))
+def test_max_errors__less_than_one__rejected(max_errors):
+    with pytest.raises(ValueError, match="max_errors must be at least 1"):
+        Serializer(BatchItem, max_errors=max_errors)
+
+
+def test_load_batch__not_list__raise_type_error():
+    with pytest.raises(TypeError):
+        Serializer(BatchItem).load_batch([])
+
+
+def test_fallback_encoder_factory():
+    class Point:
+        def __init__(self, x: int, y: int) -> None:
+            self.x = x
+            self.y = y
+
+    class PointEncoder:
+        def dump(self, value: Point) -> list[int]:
+            return [value.x, value.y]
+
+        def load(self, value: list[int]) -> Point:
+            return Point(*value)
+
+    @dataclass
+    class Shape:
+        points: list[Point]
+
+    def factory(t: Any) -> Any:
+        return PointEncoder() if t is Point else None
+
+    serializer = Serializer(Shape, fallback_encoder_factory=factory)
+    assert serializer.dump(Shape(points=[Point(1, 2)])) == {"points": [[1, 2]]}
+    loaded = serializer.load({"points": [[3, 4]]})
+    assert (loaded.points[0].x, loaded.points[0].y) == (3, 4)
+
+
+def test_fallback_encoder_factory__returns_none__raise():
+    with pytest.raises(RuntimeError, match="Unknown type <class 'complex'>"):
+        Serializer(complex, fallback_encoder_factory=lambda t: None)
+
+
+def test_nested_pydantic_model():
+    pydantic = pytest.importorskip("pydantic")
+
+    class Point(pydantic.BaseModel):
+        x: int
+        created: datetime
+
+    @dataclass
+    class Shape:
+        points: list[Point]
+
+    serializer = Serializer(Shape)
+    shape = Shape(points=[Point(x=1, created=datetime(2024, 1, 2, 3, 4, 5, tzinfo=timezone.utc))])
+    assert serializer.dump(shape) == {"points": [{"x": 1, "created": "2024-01-02T03:04:05Z"}]}
+    assert serializer.load({"points": [{"x": 1, "created": "2024-01-02T03:04:05Z"}]}) == shape
+    assert serializer.load({"points": [{"x": "1", "created": "2024-01-02T03:04:05Z"}]}, validate=False) == shape
+
+    with pytest.raises(SchemaValidationError):
+        serializer.load({"points": [{"x": "one", "created": "2024-01-02T03:04:05Z"}]})
+    with pytest.raises(ValidationError):
+        serializer.load({"points": [{"x": "one", "created": "2024-01-02T03:04:05Z"}]}, validate=False)
+
+
+@dataclass
+class Address:
+    zip_code: Annotated[str, MaxLength(5)]
+
+
+@dataclass
+class Customer:
+    address: Optional[Address]
+
+
+def test_validate_field():
+    serializer = Serializer(Customer, camelcase_fields=True)
+    assert serializer.validate_field("address.zip_code", "12345") == "12345"
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.validate_field("address.zip_code", "123456")
+    assert [e.instance_path for e in exc_info.value.errors] == ["address/zipCode"]
+
+
+def test_validate_field__unknown_field__raise():
+    with pytest.raises(KeyError):
+        Serializer(Customer).validate_field("address.city", "Moscow")
+
+
+class Status(Enum):
+    active = "active"
+
+
+@dataclass
+class Account:
+    id: UUID
+    status: Status
+    balance: Decimal
+    created: datetime
+    tags: list[str]
+    parent_id: Optional[UUID] = None
+
+
+def test_dump_db_params():
+    account = Account(
+        id=UUID("2d3e3bd3-9b7d-4c1f-8fbb-98a9e3d7b1a4"),
+        status=Status.active,
+        balance=Decimal("10.5"),
+        created=datetime(2022, 10, 10, 14, 23, 43),
+        tags=["a"],
+    )
+    serializer = Serializer(Account)
+
+    assert serializer.dump_db_params([account], ["id", "status", "balance", "created", "tags", "parent_id"]) == [
+        (
+            "2d3e3bd3-9b7d-4c1f-8fbb-98a9e3d7b1a4",
+            "active",
+            Decimal("10.5"),
+            datetime(2022, 10, 10, 14, 23, 43),
+            ["a"],
+            None,
+        )
+    ]
+    assert serializer.dump_db_params([account], ["balance", "id"], uuid_as_str=False) == [
+        (Decimal("10.5"), UUID("2d3e3bd3-9b7d-4c1f-8fbb-98a9e3d7b1a4"))
+    ]